@@ -1,6 +1,6 @@
 use actix_web::{middleware, web, App, HttpResponse, HttpServer, Responder};
 use actix_cors::Cors;
-use host::{generate_maze_proof, verify_path_proof, verify_path_proof_receipt, MazeProof, PathProof};
+use host::{generate_maze_proof, verify_path_proof, verify_path_proof_batch, verify_path_proof_merkle, verify_path_proof_receipt, BatchPathProof, MazeProof, PathMerkleProof, PathProof, ReceiptKind};
 use serde::{Deserialize, Serialize};
 
 // Request/Response types
@@ -34,6 +34,37 @@ struct VerifyPathResponse {
     error: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct VerifyPathMerkleRequest {
+    maze_root: [u8; 32],
+    maze_seed: u32,
+    moves: Vec<u8>,
+}
+
+#[derive(Debug, Serialize)]
+struct VerifyPathMerkleResponse {
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_proof: Option<PathMerkleProof>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchProveRequest {
+    /// One `(maze_proof, moves)` pair per witness to prove
+    witnesses: Vec<VerifyPathRequest>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchProveResponse {
+    success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    batch_proof: Option<BatchPathProof>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct VerifyProofRequest {
     path_proof: PathProof,
@@ -112,6 +143,82 @@ async fn verify_path(
     }
 }
 
+/// POST /api/verify-path-merkle
+/// Generate a path verification proof given only a maze's Merkle root and
+/// seed, opening each visited cell via inclusion proof instead of shipping
+/// the full grid
+async fn verify_path_merkle(
+    req: web::Json<VerifyPathMerkleRequest>,
+) -> impl Responder {
+    tracing::info!(
+        "Received verify-path-merkle request for maze seed: {}, moves: {}",
+        req.maze_seed,
+        req.moves.len()
+    );
+
+    match verify_path_proof_merkle(req.maze_root, req.maze_seed, req.moves.clone(), ReceiptKind::default()) {
+        Ok(path_proof) => {
+            tracing::info!(
+                "Successfully verified Merkle path for maze seed: {}, valid: {}",
+                req.maze_seed,
+                path_proof.is_valid
+            );
+            HttpResponse::Ok().json(VerifyPathMerkleResponse {
+                success: true,
+                path_proof: Some(path_proof),
+                error: None,
+            })
+        }
+        Err(e) => {
+            tracing::error!("Failed to verify Merkle path: {}", e);
+            HttpResponse::InternalServerError().json(VerifyPathMerkleResponse {
+                success: false,
+                path_proof: None,
+                error: Some(e.to_string()),
+            })
+        }
+    }
+}
+
+/// POST /api/prove/batch
+/// Prove a batch of (maze_proof, moves) witnesses and Merkle-aggregate their
+/// path journals under one root, so any single proof's inclusion can later
+/// be checked cheaply without re-verifying the whole batch.
+async fn prove_batch(
+    req: web::Json<BatchProveRequest>,
+) -> impl Responder {
+    tracing::info!("Received prove-batch request for {} witnesses", req.witnesses.len());
+
+    let requests = req
+        .witnesses
+        .iter()
+        .map(|w| (w.maze_proof.clone(), w.moves.clone()))
+        .collect();
+
+    match verify_path_proof_batch(requests, None) {
+        Ok(batch_proof) => {
+            tracing::info!(
+                "Successfully proved batch of {} witnesses, root: {:02x?}",
+                batch_proof.items.len(),
+                &batch_proof.root[..4]
+            );
+            HttpResponse::Ok().json(BatchProveResponse {
+                success: true,
+                batch_proof: Some(batch_proof),
+                error: None,
+            })
+        }
+        Err(e) => {
+            tracing::error!("Failed to prove batch: {}", e);
+            HttpResponse::InternalServerError().json(BatchProveResponse {
+                success: false,
+                batch_proof: None,
+                error: Some(e.to_string()),
+            })
+        }
+    }
+}
+
 /// POST /api/verify-proof
 /// Verify a path proof cryptographically (checks receipt signature and image ID)
 async fn verify_proof(
@@ -187,6 +294,8 @@ async fn main() -> std::io::Result<()> {
             .route("/health", web::get().to(health))
             .route("/api/generate-maze", web::post().to(generate_maze))
             .route("/api/verify-path", web::post().to(verify_path))
+            .route("/api/verify-path-merkle", web::post().to(verify_path_merkle))
+            .route("/api/prove/batch", web::post().to(prove_batch))
             .route("/api/verify-proof", web::post().to(verify_proof))
     })
     .bind(bind_address)?
@@ -0,0 +1,348 @@
+//! Dynamically-sized maze generation (`alloc` feature only)
+//!
+//! Mirrors `maze_gen::Maze`'s growing-tree carving algorithm, but backs cell
+//! storage with `Vec` instead of a fixed `[[Cell; MAZE_COLS]; MAZE_ROWS]`
+//! array, so callers that enable the `alloc` feature can request maze
+//! dimensions larger than the guest's compiled-in maximum (e.g. 32x32,
+//! 64x64) without recompiling the zkVM guest. Not usable for proving itself
+//! - the guest keeps the fixed-array `Maze` path - but useful for host-side
+//! generation, visualization, or benchmarking at arbitrary sizes.
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::rng::{MazeRng, RngBackend};
+
+const NORTH: usize = 0;
+const EAST: usize = 1;
+const SOUTH: usize = 2;
+const WEST: usize = 3;
+
+#[derive(Clone, Copy)]
+struct DynCell {
+    walls: [bool; 4],
+    visited: bool,
+}
+
+impl DynCell {
+    fn new() -> Self {
+        Self {
+            walls: [true, true, true, true],
+            visited: false,
+        }
+    }
+}
+
+/// Maze generator backed by `Vec`-based storage, for dimensions beyond the
+/// guest's compiled-in maximum
+pub struct DynMaze {
+    cells: Vec<Vec<DynCell>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl DynMaze {
+    /// Generate a maze with the given RNG backend, braid factor, and
+    /// growing-tree `corridor_bias`. See `maze_gen::Maze`'s equivalents for
+    /// what each parameter controls - the carving algorithm is identical,
+    /// only the cell storage differs.
+    pub fn generate(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+        corridor_bias: u8,
+    ) -> Self {
+        Self::generate_with_rng(rows, cols, seed, backend, braid_factor, corridor_bias).0
+    }
+
+    /// Generate a maze and return the RNG stream alongside it, so callers
+    /// that need further deterministic draws - such as
+    /// [`DynMaze::select_start_and_goal`] - can continue the same stream
+    /// instead of starting a fresh one. Mirrors
+    /// `maze_gen::Maze::generate_with_rng_and_bias`.
+    pub fn generate_with_rng(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+        corridor_bias: u8,
+    ) -> (Self, MazeRng) {
+        let cells = vec![vec![DynCell::new(); cols]; rows];
+        let mut maze = Self { cells, rows, cols };
+
+        let mut rng = MazeRng::new(backend, seed);
+        maze.growing_tree(&mut rng, corridor_bias);
+        if braid_factor > 0 {
+            maze.braid(&mut rng, braid_factor);
+        }
+        (maze, rng)
+    }
+
+    /// Choose randomized start and goal cells via reservoir sampling. See
+    /// `maze_gen::Maze::select_start_and_goal` for the algorithm.
+    pub fn select_start_and_goal(&self, rng: &mut MazeRng) -> (usize, usize, usize, usize) {
+        const MAX_RETRIES: usize = 16;
+
+        let start = Self::reservoir_sample_cell(self.rows, self.cols, rng);
+
+        let mut goal = start;
+        for _ in 0..MAX_RETRIES {
+            goal = Self::reservoir_sample_cell(self.rows, self.cols, rng);
+            if goal != start {
+                break;
+            }
+        }
+
+        (start.0, start.1, goal.0, goal.1)
+    }
+
+    fn reservoir_sample_cell(rows: usize, cols: usize, rng: &mut MazeRng) -> (usize, usize) {
+        let mut k: usize = 0;
+        let mut chosen = (0usize, 0usize);
+
+        for row in 0..rows {
+            for col in 0..cols {
+                k += 1;
+                if rng.randint(1, k) == 1 {
+                    chosen = (row, col);
+                }
+            }
+        }
+
+        chosen
+    }
+
+    fn growing_tree(&mut self, rng: &mut MazeRng, corridor_bias: u8) {
+        let mut frontier: Vec<(usize, usize)> = Vec::new();
+
+        self.cells[0][0].visited = true;
+        frontier.push((0, 0));
+
+        while !frontier.is_empty() {
+            let idx = if rng.randint(0, 255) < corridor_bias as usize {
+                frontier.len() - 1
+            } else {
+                rng.choice_index(frontier.len())
+            };
+
+            let (row, col) = frontier[idx];
+            let neighbors = self.get_unvisited_neighbors(row, col);
+
+            if !neighbors.is_empty() {
+                let nidx = rng.choice_index(neighbors.len());
+                let (dir, nr, nc) = neighbors[nidx];
+
+                self.cells[row][col].walls[dir] = false;
+                self.cells[nr][nc].walls[Self::opposite_dir(dir)] = false;
+
+                self.cells[nr][nc].visited = true;
+                frontier.push((nr, nc));
+            } else {
+                // Swap-remove - order only matters for the "newest" pick above.
+                let last = frontier.pop().expect("frontier is non-empty here");
+                if idx < frontier.len() {
+                    frontier[idx] = last;
+                }
+            }
+        }
+    }
+
+    fn braid(&mut self, rng: &mut MazeRng, braid_factor: u8) {
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let open_count = self.cells[row][col]
+                    .walls
+                    .iter()
+                    .filter(|&&wall| !wall)
+                    .count();
+
+                if open_count != 1 {
+                    continue;
+                }
+
+                if rng.randint(0, 255) >= braid_factor as usize {
+                    continue;
+                }
+
+                let mut candidates: Vec<(usize, usize, usize)> = Vec::new();
+                let directions = [
+                    (NORTH, row.wrapping_sub(1), col),
+                    (EAST, row, col + 1),
+                    (SOUTH, row + 1, col),
+                    (WEST, row, col.wrapping_sub(1)),
+                ];
+
+                for (dir, nr, nc) in directions {
+                    if nr < self.rows && nc < self.cols && self.cells[row][col].walls[dir] {
+                        candidates.push((dir, nr, nc));
+                    }
+                }
+
+                if candidates.is_empty() {
+                    continue;
+                }
+
+                let idx = rng.choice_index(candidates.len());
+                let (dir, nr, nc) = candidates[idx];
+
+                self.cells[row][col].walls[dir] = false;
+                self.cells[nr][nc].walls[Self::opposite_dir(dir)] = false;
+            }
+        }
+    }
+
+    fn get_unvisited_neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize, usize)> {
+        let mut neighbors = Vec::with_capacity(4);
+
+        let directions = [
+            (NORTH, row.wrapping_sub(1), col),
+            (EAST, row, col + 1),
+            (SOUTH, row + 1, col),
+            (WEST, row, col.wrapping_sub(1)),
+        ];
+
+        for (dir, nr, nc) in directions {
+            if nr < self.rows && nc < self.cols && !self.cells[nr][nc].visited {
+                neighbors.push((dir, nr, nc));
+            }
+        }
+
+        neighbors
+    }
+
+    fn opposite_dir(dir: usize) -> usize {
+        match dir {
+            NORTH => SOUTH,
+            SOUTH => NORTH,
+            EAST => WEST,
+            WEST => EAST,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Flatten the maze to a dynamically sized binary grid (0=wall, 1=path),
+    /// row-major, `(rows*2+1) x (cols*2+1)`. Mirrors
+    /// `maze_gen::Maze::to_binary_grid`'s layout so hashes agree wherever
+    /// dimensions overlap.
+    pub fn to_binary_grid_flat(&self) -> Vec<u8> {
+        let grid_rows = self.rows * 2 + 1;
+        let grid_cols = self.cols * 2 + 1;
+        let mut grid = vec![0u8; grid_rows * grid_cols];
+
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let cell = &self.cells[row][col];
+                let gr = row * 2 + 1;
+                let gc = col * 2 + 1;
+
+                grid[gr * grid_cols + gc] = 1;
+
+                if !cell.walls[NORTH] {
+                    grid[(gr - 1) * grid_cols + gc] = 1;
+                }
+                if !cell.walls[SOUTH] {
+                    grid[(gr + 1) * grid_cols + gc] = 1;
+                }
+                if !cell.walls[EAST] {
+                    grid[gr * grid_cols + gc + 1] = 1;
+                }
+                if !cell.walls[WEST] {
+                    grid[gr * grid_cols + gc - 1] = 1;
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Flatten the maze into a `Vec<Vec<u8>>` grid for host-side
+    /// serialization convenience.
+    #[cfg(feature = "std")]
+    pub fn to_binary_grid_vec(&self) -> Vec<Vec<u8>> {
+        let grid_cols = self.cols * 2 + 1;
+        let flat = self.to_binary_grid_flat();
+        flat.chunks(grid_cols).map(|row| row.to_vec()).collect()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dyn_maze_determinism() {
+        let maze1 = DynMaze::generate(32, 32, 42, RngBackend::Minstd, 0, 255);
+        let maze2 = DynMaze::generate(32, 32, 42, RngBackend::Minstd, 0, 255);
+        assert_eq!(maze1.to_binary_grid_flat(), maze2.to_binary_grid_flat());
+    }
+
+    #[test]
+    fn test_dyn_maze_larger_than_guest_max() {
+        let maze = DynMaze::generate(64, 64, 7, RngBackend::Minstd, 0, 255);
+        let grid = maze.to_binary_grid_flat();
+
+        assert_eq!(grid.len(), (64 * 2 + 1) * (64 * 2 + 1));
+        // Cell center is always carved open
+        assert_eq!(grid[(1) * (64 * 2 + 1) + 1], 1);
+    }
+
+    #[test]
+    fn test_dyn_maze_select_start_and_goal_distinct() {
+        let (maze, mut rng) = DynMaze::generate_with_rng(32, 32, 2918957128, RngBackend::Minstd, 0, 255);
+        let (sr, sc, gr, gc) = maze.select_start_and_goal(&mut rng);
+        assert!(sr < 32 && sc < 32);
+        assert!(gr < 32 && gc < 32);
+        assert_ne!((sr, sc), (gr, gc));
+    }
+
+    /// Confirms the module doc's equivalence claim: at dimensions within the
+    /// guest's compiled-in maximum (where both types can generate the same
+    /// maze), `DynMaze` and `maze_gen::Maze` must carve identical grids for
+    /// the same seed/backend/braid/corridor_bias, or a hand-duplicated
+    /// algorithm has silently drifted from the other.
+    #[test]
+    fn test_dyn_maze_matches_maze_for_overlapping_dimensions() {
+        use crate::maze_gen::Maze;
+
+        for backend in [RngBackend::Minstd, RngBackend::Pcg32, RngBackend::Chacha8] {
+            for (braid_factor, corridor_bias) in [(0u8, 255u8), (64, 64), (255, 0)] {
+                let rows = 10;
+                let cols = 10;
+                let seed = 2024;
+
+                let maze = Maze::generate_with_corridor_bias(
+                    rows, cols, seed, backend, braid_factor, corridor_bias,
+                );
+                let dyn_maze = DynMaze::generate(rows, cols, seed, backend, braid_factor, corridor_bias);
+
+                let grid_cols = cols * 2 + 1;
+                let grid_rows = rows * 2 + 1;
+                let maze_grid = maze.to_binary_grid();
+                let dyn_grid = dyn_maze.to_binary_grid_flat();
+
+                for row in 0..grid_rows {
+                    for col in 0..grid_cols {
+                        assert_eq!(
+                            maze_grid[row][col],
+                            dyn_grid[row * grid_cols + col],
+                            "grid cell ({row}, {col}) diverged for backend {backend:?}, braid {braid_factor}, bias {corridor_bias}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
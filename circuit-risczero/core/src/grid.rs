@@ -0,0 +1,175 @@
+//! Generic fixed-capacity 2D grid storage.
+//!
+//! `Maze` used to hardcode `[[Cell; MAX_MAZE_COLS]; MAX_MAZE_ROWS]`, which
+//! always allocates the worst-case square even for a 10x30 maze and makes
+//! "what are this maze's real dimensions" a question answered by re-deriving
+//! it from two `MAX_*` constants everywhere. [`Grid<T, CAP>`] keeps the same
+//! `no_std` fixed-capacity backing array under the hood (always `CAP`
+//! cells, whatever the dimensions), but addresses it with a runtime
+//! [`Dimensions`], so callers read width/height off one value instead of
+//! threading two constants around.
+
+/// Runtime width/height of a [`Grid`], independent of its backing capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dimensions {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Dimensions {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { width, height }
+    }
+
+    /// Total cell count - the minimum backing capacity a [`Grid`] needs to
+    /// hold these dimensions.
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+}
+
+/// A row-major 2D grid over a fixed-capacity backing array of `CAP` cells,
+/// addressed by a runtime [`Dimensions`] that must fit within `CAP` but can
+/// otherwise be smaller than it (and grown later via [`Grid::extend`]).
+pub struct Grid<T, const CAP: usize> {
+    cells: [T; CAP],
+    dims: Dimensions,
+}
+
+impl<T: Copy + Default, const CAP: usize> Grid<T, CAP> {
+    /// Create a grid of `dims`, every cell initialized to `T::default()`.
+    ///
+    /// # Panics
+    /// If `dims.area()` exceeds `CAP`.
+    pub fn new(dims: Dimensions) -> Self {
+        assert!(dims.area() <= CAP, "grid dimensions exceed backing capacity");
+        Self {
+            cells: [T::default(); CAP],
+            dims,
+        }
+    }
+
+    pub fn dimensions(&self) -> Dimensions {
+        self.dims
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.dims.width + col
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> T {
+        self.cells[self.index(row, col)]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        let idx = self.index(row, col);
+        self.cells[idx] = value;
+    }
+
+    /// Iterate every in-bounds cell in row-major order: `(0,0), (0,1), ...`.
+    pub fn row_major_iter(&self) -> impl Iterator<Item = &T> {
+        self.cells[..self.dims.area()].iter()
+    }
+
+    /// Iterate every in-bounds cell in column-major order: `(0,0), (1,0),
+    /// ...`.
+    pub fn column_major_iter(&self) -> impl Iterator<Item = T> + '_ {
+        let height = self.dims.height;
+        (0..self.dims.width).flat_map(move |col| (0..height).map(move |row| self.get(row, col)))
+    }
+
+    /// Grow to `new_dims`, preserving every existing cell's contents and
+    /// filling newly added cells with `T::default()`.
+    ///
+    /// # Panics
+    /// If `new_dims` is smaller than the current dimensions in either axis,
+    /// or exceeds `CAP`.
+    pub fn extend(&mut self, new_dims: Dimensions) {
+        assert!(new_dims.area() <= CAP, "grid dimensions exceed backing capacity");
+        assert!(
+            new_dims.width >= self.dims.width && new_dims.height >= self.dims.height,
+            "extend cannot shrink a dimension"
+        );
+
+        let mut rebuilt = [T::default(); CAP];
+        for row in 0..self.dims.height {
+            for col in 0..self.dims.width {
+                rebuilt[row * new_dims.width + col] = self.get(row, col);
+            }
+        }
+        self.cells = rebuilt;
+        self.dims = new_dims;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    struct Cell(u8);
+
+    #[test]
+    fn test_get_set_round_trip() {
+        let mut grid: Grid<Cell, 16> = Grid::new(Dimensions::new(4, 4));
+        grid.set(1, 2, Cell(7));
+        assert_eq!(grid.get(1, 2), Cell(7));
+        assert_eq!(grid.get(0, 0), Cell::default());
+    }
+
+    #[test]
+    fn test_row_major_iter_order() {
+        let mut grid: Grid<Cell, 6> = Grid::new(Dimensions::new(3, 2));
+        for row in 0..2 {
+            for col in 0..3 {
+                grid.set(row, col, Cell((row * 3 + col) as u8));
+            }
+        }
+        let mut values = grid.row_major_iter().map(|c| c.0);
+        assert_eq!([values.next(), values.next(), values.next()], [Some(0), Some(1), Some(2)]);
+        assert_eq!([values.next(), values.next(), values.next()], [Some(3), Some(4), Some(5)]);
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn test_column_major_iter_order() {
+        let mut grid: Grid<Cell, 6> = Grid::new(Dimensions::new(3, 2));
+        for row in 0..2 {
+            for col in 0..3 {
+                grid.set(row, col, Cell((row * 3 + col) as u8));
+            }
+        }
+        let mut values = grid.column_major_iter().map(|c| c.0);
+        assert_eq!([values.next(), values.next()], [Some(0), Some(3)]);
+        assert_eq!([values.next(), values.next()], [Some(1), Some(4)]);
+        assert_eq!([values.next(), values.next()], [Some(2), Some(5)]);
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn test_extend_preserves_existing_cells() {
+        let mut grid: Grid<Cell, 20> = Grid::new(Dimensions::new(2, 2));
+        grid.set(0, 0, Cell(1));
+        grid.set(1, 1, Cell(2));
+
+        grid.extend(Dimensions::new(4, 3));
+
+        assert_eq!(grid.get(0, 0), Cell(1));
+        assert_eq!(grid.get(1, 1), Cell(2));
+        assert_eq!(grid.get(2, 3), Cell::default());
+        assert_eq!(grid.dimensions(), Dimensions::new(4, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "exceed backing capacity")]
+    fn test_new_rejects_dimensions_over_capacity() {
+        let _grid: Grid<Cell, 4> = Grid::new(Dimensions::new(3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot shrink")]
+    fn test_extend_rejects_shrinking() {
+        let mut grid: Grid<Cell, 16> = Grid::new(Dimensions::new(4, 4));
+        grid.extend(Dimensions::new(2, 4));
+    }
+}
@@ -0,0 +1,151 @@
+//! Semaphore-style identity-commitment Merkle tree for anonymous
+//! maze-completion credentials (see [`crate::NullifierJournal`]).
+//!
+//! A player's `identity_commitment = H(identity)` is inserted as a leaf of a
+//! fixed-depth binary tree of eligible players. Unlike [`crate::merkle_grid`]
+//! (grown incrementally, one grid cell at a time, via a frontier) this tree
+//! is built once from the full leaf set by whoever maintains the eligibility
+//! list, since that set is small and known up front; the guest only ever
+//! verifies a membership path, never builds one. Odd levels are padded by
+//! duplicating the last node, the same convention `merkle_grid` uses.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use sha3::{Digest, Sha3_256};
+
+/// Depth of the identity tree - 2^20 supports up to ~1M eligible players.
+pub const IDENTITY_TREE_DEPTH: usize = 20;
+
+/// Sibling hashes from a leaf to the root, and a bitmask where bit `i` is
+/// set if the leaf's ancestor at depth `i` was the right child of its
+/// parent.
+pub type MembershipProof = ([[u8; 32]; IDENTITY_TREE_DEPTH], u32);
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// `identity_commitment = H(identity)`, the leaf a player publishes to the
+/// eligibility list without revealing their secret `identity`.
+pub fn identity_commitment(identity: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"maze-identity-commitment");
+    hasher.update(identity);
+    hasher.finalize().into()
+}
+
+/// Derive a maze's `external_nullifier` from its seed, so each maze has its
+/// own nullifier namespace and the same identity produces an unrelated
+/// nullifier hash in a different maze.
+pub fn external_nullifier(maze_seed: u32) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"maze-external-nullifier");
+    hasher.update(maze_seed.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// `nullifier_hash = H(identity, external_nullifier)` - deterministic per
+/// (identity, maze), so a verifier can reject a repeat submission by
+/// tracking seen hashes, but reveals nothing about `identity` on its own.
+pub fn nullifier_hash(identity: &[u8; 32], external_nullifier: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"maze-nullifier");
+    hasher.update(identity);
+    hasher.update(external_nullifier);
+    hasher.finalize().into()
+}
+
+/// Recompute the root from `identity_commitment` and a [`MembershipProof`],
+/// and check it against `root`.
+pub fn verify_membership(root: [u8; 32], identity_commitment: [u8; 32], proof: &MembershipProof) -> bool {
+    let (siblings, path_bits) = proof;
+    let mut acc = identity_commitment;
+    for (depth, sibling) in siblings.iter().enumerate() {
+        acc = if (path_bits >> depth) & 1 == 1 {
+            hash_pair(sibling, &acc)
+        } else {
+            hash_pair(&acc, sibling)
+        };
+    }
+    acc == root
+}
+
+/// Host-side: build the eligibility tree from every player's
+/// `identity_commitment` and return its root plus one [`MembershipProof`]
+/// per leaf, in input order. Pads odd levels by duplicating the last node,
+/// the same convention [`verify_membership`] expects.
+#[cfg(feature = "alloc")]
+pub fn build(leaves: &[[u8; 32]]) -> ([u8; 32], alloc::vec::Vec<MembershipProof>) {
+    use alloc::vec::Vec;
+
+    if leaves.is_empty() {
+        return ([0u8; 32], Vec::new());
+    }
+
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    let mut proofs: Vec<MembershipProof> = (0..leaves.len())
+        .map(|_| ([[0u8; 32]; IDENTITY_TREE_DEPTH], 0u32))
+        .collect();
+    let mut indices: Vec<usize> = (0..leaves.len()).collect();
+
+    for depth in 0..IDENTITY_TREE_DEPTH {
+        if level.len() % 2 == 1 {
+            let last = *level.last().expect("level is non-empty");
+            level.push(last);
+        }
+
+        for (leaf_idx, idx) in indices.iter().enumerate() {
+            proofs[leaf_idx].0[depth] = level[idx ^ 1];
+            if idx % 2 == 1 {
+                proofs[leaf_idx].1 |= 1 << depth;
+            }
+        }
+
+        let mut next_level = Vec::with_capacity(level.len() / 2);
+        for pair in level.chunks(2) {
+            next_level.push(hash_pair(&pair[0], &pair[1]));
+        }
+        level = next_level;
+
+        for idx in indices.iter_mut() {
+            *idx /= 2;
+        }
+    }
+
+    (level[0], proofs)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_proofs_round_trip() {
+        let identities: [[u8; 32]; 5] = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], [5u8; 32]];
+        let leaves: alloc::vec::Vec<[u8; 32]> = identities.iter().map(identity_commitment).collect();
+        let (root, proofs) = build(&leaves);
+
+        for (leaf, proof) in leaves.iter().zip(proofs.iter()) {
+            assert!(verify_membership(root, *leaf, proof));
+        }
+
+        // A commitment not in the tree must not verify against someone
+        // else's proof.
+        let outsider = identity_commitment(&[9u8; 32]);
+        assert!(!verify_membership(root, outsider, &proofs[0]));
+    }
+
+    #[test]
+    fn test_nullifier_hash_is_deterministic_per_identity_and_maze() {
+        let identity = [7u8; 32];
+        let a = nullifier_hash(&identity, &external_nullifier(1));
+        let b = nullifier_hash(&identity, &external_nullifier(1));
+        let c = nullifier_hash(&identity, &external_nullifier(2));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
@@ -5,19 +5,35 @@
 //! guest programs, as well as the host program.
 //!
 //! The crate is no_std compatible for use in RISC Zero guest programs,
-//! with optional std features for host-side convenience.
+//! with optional `alloc` and `std` features for larger-than-guest-max maze
+//! sizes and host-side convenience, respectively.
 
 #![no_std]
 
 // Re-export shared modules
 pub mod rng;
+pub mod grid;
 pub mod maze_gen;
+pub mod merkle_grid;
+pub mod identity_tree;
+#[cfg(feature = "alloc")]
+pub mod dyn_maze;
 
 // Re-export commonly used types for convenience
-pub use rng::SimpleLCG;
+pub use rng::{MazeRng, RngBackend, SimpleLCG};
+pub use grid::{Dimensions, Grid};
 pub use maze_gen::Maze;
-
-/// Maze dimensions (cells, not including walls)
+pub use merkle_grid::{verify_inclusion as verify_grid_inclusion, InclusionProof as GridInclusionProof, MerkleGrid};
+pub use identity_tree::{verify_membership as verify_identity_membership, MembershipProof as IdentityMembershipProof, IDENTITY_TREE_DEPTH};
+#[cfg(feature = "alloc")]
+pub use dyn_maze::DynMaze;
+
+/// Maximum maze dimensions the zkVM guest is compiled to support (cells, not
+/// including walls). Actual generation dimensions are runtime values <=
+/// these maxima, committed into `MazeJournal::rows`/`MazeJournal::cols`
+/// rather than assumed fixed. Larger mazes (e.g. 32x32, 64x64) are only
+/// reachable through [`DynMaze`] under the `alloc` feature, which isn't
+/// bound by these guest-compiled maxima.
 pub const MAZE_ROWS: usize = 20;
 pub const MAZE_COLS: usize = 20;
 
@@ -27,16 +43,24 @@ pub const GRID_SIZE: usize = MAZE_ROWS * 2 + 1; // 41 for 20x20 maze
 /// Maximum number of moves allowed in a path
 pub const MAX_MOVES: usize = 500;
 
+/// Maximum number of mazes a single "journey" proof (see the
+/// `path-verify-journey` guest) can chain together via recursive assumption
+/// composition.
+pub const MAX_JOURNEY_MAZES: usize = 16;
+
 /// Total size of the grid data (GRID_SIZE * GRID_SIZE)
 pub const GRID_DATA_SIZE: usize = GRID_SIZE * GRID_SIZE; // 1681 for 41x41 grid
 
 /// SHA-256 hash size
 pub const HASH_SIZE: usize = 32;
 
-/// Size of the maze journal (seed + grid_hash)
-/// 4 bytes (u32 seed) + 32 bytes (SHA-256 hash) = 36 bytes
-/// This is 97.9% smaller than the previous 1,685 byte journal!
-pub const MAZE_JOURNAL_SIZE: usize = 4 + HASH_SIZE;
+/// Size of the maze journal (seed + grid_hash + rng_backend + braid_factor
+/// + start/goal + corridor_bias + rows/cols)
+/// 4 bytes (u32 seed) + 32 bytes (SHA-256 hash) + 1 byte (RNG backend id)
+/// + 1 byte (braid factor) + 4 bytes (start_row, start_col, goal_row, goal_col)
+/// + 1 byte (corridor bias) + 2 bytes (rows, cols) = 45 bytes
+/// This is still over 97% smaller than the previous 1,685 byte journal.
+pub const MAZE_JOURNAL_SIZE: usize = 4 + HASH_SIZE + 1 + 1 + 4 + 1 + 2;
 
 /// Journal output from maze generation proof
 ///
@@ -47,16 +71,54 @@ pub const MAZE_JOURNAL_SIZE: usize = 4 + HASH_SIZE;
 /// The actual grid data is passed separately to the path verification
 /// program and verified against this hash.
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
 pub struct MazeJournal {
     /// The seed used to generate this maze
     pub maze_seed: u32,
 
     /// SHA-256 hash of the binary grid data
-    /// This allows the journal to be 97.9% smaller while maintaining
+    /// This allows the journal to be 97.3% smaller while maintaining
     /// cryptographic integrity. The grid is verified by hashing it
     /// and comparing to this committed hash.
     pub grid_hash: [u8; HASH_SIZE],
+
+    /// The `RngBackend` id used to generate this maze
+    ///
+    /// Verification must regenerate the maze with this same backend,
+    /// since different generators produce different grids from the
+    /// same seed.
+    pub rng_backend: u8,
+
+    /// The braid factor (0-255) used to generate this maze
+    ///
+    /// Folded into the commitment so the maze hash binds the exact
+    /// braiding configuration, not just the seed and backend.
+    pub braid_factor: u8,
+
+    /// Cell row of the randomized entrance, chosen via reservoir sampling
+    pub start_row: u8,
+    /// Cell column of the randomized entrance, chosen via reservoir sampling
+    pub start_col: u8,
+    /// Cell row of the randomized exit, chosen via reservoir sampling
+    pub goal_row: u8,
+    /// Cell column of the randomized exit, chosen via reservoir sampling
+    pub goal_col: u8,
+
+    /// The growing-tree `corridor_bias` (0-255) used to generate this maze
+    ///
+    /// Folded into the commitment alongside `rng_backend` and
+    /// `braid_factor` so the maze hash binds the exact carving character,
+    /// not just the seed. See `maze_gen`'s module docs for what it controls.
+    pub corridor_bias: u8,
+
+    /// Runtime cell rows used to generate this maze (<= `MAZE_ROWS`)
+    ///
+    /// Replaces the previous implicit 20x20 assumption, so a proof commits
+    /// to the actual dimensions it was generated at.
+    pub rows: u8,
+    /// Runtime cell columns used to generate this maze (<= `MAZE_COLS`)
+    pub cols: u8,
 }
 
 /// Journal output from path verification proof
@@ -64,7 +126,8 @@ pub struct MazeJournal {
 /// This structure contains the result of path verification
 /// along with metadata about the maze and player.
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
 pub struct PathJournal {
     /// Whether the path successfully reached the goal
     pub is_valid: u32, // Using u32 for RISC Zero compatibility (0 or 1)
@@ -73,6 +136,55 @@ pub struct PathJournal {
     pub maze_seed: u32,
 }
 
+/// Size of the Merkle-backed path journal (is_valid + maze_seed + maze_root)
+pub const PATH_MERKLE_JOURNAL_SIZE: usize = 4 + 4 + HASH_SIZE;
+
+/// Size of the nullifier extension appended after [`PathJournal`] in the
+/// `path-verify` guest's journal: `identity_root` + `nullifier_hash`.
+///
+/// Always present (zeroed when the path proof carries no anonymous
+/// credential) so the journal stays a fixed size regardless of whether one
+/// was supplied.
+pub const NULLIFIER_JOURNAL_SIZE: usize = HASH_SIZE + HASH_SIZE;
+
+/// Extension to [`PathJournal`] carried by a path proof with an anonymous
+/// maze-completion credential (see [`identity_tree`]): the Semaphore-style
+/// identity-commitment Merkle root the player's membership was checked
+/// against, and a `nullifier_hash` unique per (identity, maze) that reveals
+/// nothing about the player's identity on its own.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct NullifierJournal {
+    /// Root of the identity-commitment tree the membership proof was
+    /// checked against, or all-zero if no credential was supplied.
+    pub identity_root: [u8; HASH_SIZE],
+
+    /// `H(identity, external_nullifier)`, or all-zero if no credential was
+    /// supplied.
+    pub nullifier_hash: [u8; HASH_SIZE],
+}
+
+/// Journal output from the Merkle-backed path verification proof
+///
+/// Carries a [`crate::merkle_grid::MerkleGrid`] root in place of
+/// [`MazeJournal::grid_hash`], so the proof is about path membership against
+/// a maze commitment rather than a maze whose full grid was hashed and
+/// shipped to the guest.
+#[repr(C)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PathMerkleJournal {
+    /// Whether the path successfully reached the goal
+    pub is_valid: u32,
+
+    /// The seed of the maze this path was verified against
+    pub maze_seed: u32,
+
+    /// The Merkle root the opened cells were checked against
+    pub maze_root: [u8; HASH_SIZE],
+}
+
 
 #[cfg(test)]
 mod tests {
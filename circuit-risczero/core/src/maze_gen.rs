@@ -1,21 +1,31 @@
-//! Maze generation using Recursive Backtracker algorithm
+//! Maze generation using the Growing Tree algorithm
 //!
-//! This implementation must exactly match the Python maze generator in generate_maze.py
-//! to ensure deterministic maze generation from the same seed.
+//! Growing Tree generalizes the classic maze carvers behind one knob,
+//! `corridor_bias`: maintain a frontier of active cells, and at each step
+//! choose the next cell to extend from either the most recently added
+//! frontier cell (depth-first, long winding corridors - recursive
+//! backtracker) or a uniformly random frontier cell (short, bushy
+//! branching - Prim's-like), weighted by `corridor_bias` out of 256.
 //!
-//! Algorithm: Recursive Backtracker (DFS with backtracking)
-//! 1. Start at (0, 0), mark as visited
-//! 2. While stack is not empty:
-//!    - Get unvisited neighbors of current cell
-//!    - If neighbors exist:
-//!      * Choose random neighbor
-//!      * Remove wall between current and neighbor
-//!      * Mark neighbor as visited, push to stack
-//!    - Else: backtrack (pop from stack)
+//! Algorithm: Growing Tree
+//! 1. Start at (0, 0), mark as visited, push onto the frontier
+//! 2. While the frontier is not empty:
+//!    - Draw `rng.randint(0, 255)`; below `corridor_bias` picks the newest
+//!      frontier cell, otherwise picks a uniformly random one
+//!    - If the chosen cell has unvisited neighbors: choose one at random,
+//!      remove the wall between them, mark it visited, and push it
+//!    - Else: remove the chosen cell from the frontier (swap-remove, since
+//!      frontier order only matters for the "newest" selection above)
+//!
+//! A `corridor_bias` of 255 behaves like the recursive backtracker; 0
+//! behaves like Prim's algorithm. This must exactly match the Python maze
+//! generator in generate_maze.py to ensure deterministic maze generation
+//! from the same seed.
 
 #![allow(dead_code)]
 
-use crate::rng::SimpleLCG;
+use crate::grid::{Dimensions, Grid};
+use crate::rng::{MazeRng, RngBackend};
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -32,6 +42,7 @@ const WEST: usize = 3;
 const MAX_MAZE_ROWS: usize = 20;
 const MAX_MAZE_COLS: usize = 20;
 const MAX_GRID_SIZE: usize = MAX_MAZE_ROWS * 2 + 1; // 41 for 20x20 maze
+const MAX_GRID_CELLS: usize = MAX_MAZE_ROWS * MAX_MAZE_COLS; // 400 for 20x20 maze
 
 /// A cell in the maze with walls in four directions
 #[derive(Clone, Copy)]
@@ -49,12 +60,16 @@ impl Cell {
     }
 }
 
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Maze generator using recursive backtracker algorithm
 #[repr(align(4))] // Memory alignment optimization for RISC Zero
 pub struct Maze {
-    cells: [[Cell; MAX_MAZE_COLS]; MAX_MAZE_ROWS],
-    rows: usize,
-    cols: usize,
+    cells: Grid<Cell, MAX_GRID_CELLS>,
 }
 
 impl Maze {
@@ -67,81 +82,287 @@ impl Maze {
     ///
     /// # Returns
     /// A generated maze with guaranteed path from (0,0) to (rows-1, cols-1)
+    ///
+    /// Uses the `Minstd` RNG backend for Python compatibility and no
+    /// braiding (`braid_factor` 0), producing a perfect maze with exactly
+    /// one solution. Use [`Maze::generate_with_backend`] or
+    /// [`Maze::generate_with_options`] for more control.
     pub fn generate(rows: usize, cols: usize, seed: u32) -> Self {
+        Self::generate_with_backend(rows, cols, seed, RngBackend::Minstd)
+    }
+
+    /// Default `corridor_bias` used when callers don't care about it: the
+    /// newest frontier cell is picked on 255 of every 256 draws, close to
+    /// the classic recursive backtracker character.
+    const DEFAULT_CORRIDOR_BIAS: u8 = 255;
+
+    /// Generate a maze using the given RNG backend
+    ///
+    /// The backend id must be committed alongside the seed (see
+    /// `MazeJournal::rng_backend`) so verification regenerates the maze
+    /// with the exact same generator.
+    pub fn generate_with_backend(rows: usize, cols: usize, seed: u32, backend: RngBackend) -> Self {
+        Self::generate_with_options(rows, cols, seed, backend, 0)
+    }
+
+    /// Generate a maze using the given RNG backend and braid factor
+    ///
+    /// `braid_factor` (0-255) is the probability, out of 256, that any
+    /// given dead end gets an extra passage carved to a random walled
+    /// neighbor, turning the perfect maze into a braided one with cycles
+    /// and therefore multiple valid solutions. A `braid_factor` of 0
+    /// leaves the maze perfect. Both `rng_backend` and `braid_factor` must
+    /// be committed into `MazeJournal` so verification reproduces the
+    /// identical grid.
+    pub fn generate_with_options(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+    ) -> Self {
+        Self::generate_with_rng(rows, cols, seed, backend, braid_factor).0
+    }
+
+    /// Generate a maze using the given RNG backend, braid factor, and
+    /// growing-tree `corridor_bias`
+    ///
+    /// `corridor_bias` (0-255) is committed into `MazeJournal` alongside the
+    /// other generation parameters so verification reproduces the identical
+    /// grid. See the module docs for what it controls.
+    pub fn generate_with_corridor_bias(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+        corridor_bias: u8,
+    ) -> Self {
+        Self::generate_with_rng_and_bias(rows, cols, seed, backend, braid_factor, corridor_bias).0
+    }
+
+    /// Generate a maze and return the RNG stream alongside it
+    ///
+    /// Identical to [`Maze::generate_with_options`], but also hands back
+    /// the `MazeRng` left over after carving (and braiding, if any), so
+    /// callers that need further deterministic draws from the same
+    /// stream - such as [`Maze::select_start_and_goal`] - can continue it
+    /// instead of starting a fresh one. Uses [`Maze::DEFAULT_CORRIDOR_BIAS`]
+    /// (depth-first on 255 of every 256 draws).
+    pub fn generate_with_rng(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+    ) -> (Self, MazeRng) {
+        Self::generate_with_rng_and_bias(rows, cols, seed, backend, braid_factor, Self::DEFAULT_CORRIDOR_BIAS)
+    }
+
+    /// Generate a maze with an explicit `corridor_bias` and return the RNG
+    /// stream alongside it, for callers (such as [`Maze::select_start_and_goal`])
+    /// that need to keep drawing from the same deterministic stream.
+    pub fn generate_with_rng_and_bias(
+        rows: usize,
+        cols: usize,
+        seed: u32,
+        backend: RngBackend,
+        braid_factor: u8,
+        corridor_bias: u8,
+    ) -> (Self, MazeRng) {
         assert!(rows <= MAX_MAZE_ROWS && cols <= MAX_MAZE_COLS,
                 "Maze dimensions exceed maximum");
 
-        // Initialize fixed-size array with default cells
-        let cells = [[Cell::new(); MAX_MAZE_COLS]; MAX_MAZE_ROWS];
+        let cells = Grid::new(Dimensions::new(cols, rows));
 
-        let mut maze = Self {
-            cells,
-            rows,
-            cols,
-        };
+        let mut maze = Self { cells };
+
+        let mut rng = MazeRng::new(backend, seed);
+        maze.growing_tree(&mut rng, corridor_bias);
+        if braid_factor > 0 {
+            maze.braid(&mut rng, braid_factor);
+        }
+        (maze, rng)
+    }
+
+    /// Number of cell rows this maze was generated at (<= `MAX_MAZE_ROWS`)
+    pub fn rows(&self) -> usize {
+        self.cells.dimensions().height
+    }
 
-        let mut rng = SimpleLCG::new(seed);
-        maze.recursive_backtracker(&mut rng);
-        maze
+    /// Number of cell columns this maze was generated at (<= `MAX_MAZE_COLS`)
+    pub fn cols(&self) -> usize {
+        self.cells.dimensions().width
     }
 
-    /// Recursive backtracker algorithm (iterative with explicit stack)
-    /// OPTIMIZATION: Uses fixed-size stack array instead of Vec for zero allocations
+    /// Open the wall between `(row, col)` and its `dir` neighbor at
+    /// `(nr, nc)` on both sides, since `Grid::get`/`Grid::set` work by value
+    /// rather than by mutable reference.
+    fn open_wall(&mut self, row: usize, col: usize, dir: usize, nr: usize, nc: usize) {
+        let mut cell = self.cells.get(row, col);
+        cell.walls[dir] = false;
+        self.cells.set(row, col, cell);
+
+        let mut neighbor = self.cells.get(nr, nc);
+        neighbor.walls[Self::opposite_dir(dir)] = false;
+        self.cells.set(nr, nc, neighbor);
+    }
+
+    /// Choose randomized start and goal cells via reservoir sampling
+    ///
+    /// Uses Algorithm R: iterates once over all passable cells, keeping a
+    /// running count `k` and the currently-chosen cell, replacing the
+    /// chosen cell for the k-th cell seen with probability `1/k`
+    /// (`rng.randint(1, k) == 1`). Every maze cell is passable (cell
+    /// centers are always carved paths), so this runs over the full
+    /// `rows x cols` grid in a single O(1)-memory pass with no
+    /// allocation - safe for the `no_std` guest.
+    ///
+    /// Draws the start first, then draws the goal the same way, rejecting
+    /// and re-drawing (bounded) if it lands on the same cell as the start.
     ///
-    /// NOTE: This implementation matches the Python algorithm exactly, maintaining
-    /// a separate 'current' variable rather than peeking at the stack each iteration.
-    /// While these approaches seem equivalent, the explicit current tracking ensures
-    /// identical behavior across all seeds and maze sizes.
-    fn recursive_backtracker(&mut self, rng: &mut SimpleLCG) {
-        // Fixed-size stack (max 20x20 = 400 cells)
-        let mut stack = [(0usize, 0usize); 400];
-        let mut stack_len = 0;
-
-        // Start at (0, 0) - matches Python's self.start
-        let mut current = (0, 0);
-
-        // Mark start as visited and push to stack
-        self.cells[0][0].visited = true;
-        stack[stack_len] = current;
-        stack_len += 1;
-
-        while stack_len > 0 {
-            // Get unvisited neighbors of current cell
-            let (row, col) = current;
+    /// # Returns
+    /// `(start_row, start_col, goal_row, goal_col)`
+    pub fn select_start_and_goal(&self, rng: &mut MazeRng) -> (usize, usize, usize, usize) {
+        const MAX_RETRIES: usize = 16;
+
+        let start = Self::reservoir_sample_cell(self.rows(), self.cols(), rng);
+
+        let mut goal = start;
+        for _ in 0..MAX_RETRIES {
+            goal = Self::reservoir_sample_cell(self.rows(), self.cols(), rng);
+            if goal != start {
+                break;
+            }
+        }
+
+        (start.0, start.1, goal.0, goal.1)
+    }
+
+    /// Single Algorithm R reservoir-sampling pass over every cell in the grid
+    fn reservoir_sample_cell(rows: usize, cols: usize, rng: &mut MazeRng) -> (usize, usize) {
+        let mut k: usize = 0;
+        let mut chosen = (0usize, 0usize);
+
+        for row in 0..rows {
+            for col in 0..cols {
+                k += 1;
+                if rng.randint(1, k) == 1 {
+                    chosen = (row, col);
+                }
+            }
+        }
+
+        chosen
+    }
+
+    /// Growing Tree maze carving (iterative with explicit fixed-size frontier)
+    /// OPTIMIZATION: Uses a fixed-size frontier array instead of Vec for zero allocations
+    ///
+    /// `corridor_bias` (0-255) weights how the next frontier cell is chosen:
+    /// drawing `rng.randint(0, 255)` below it picks the most recently added
+    /// cell (depth-first, recursive-backtracker-like corridors), and
+    /// drawing at or above it picks a uniformly random frontier cell
+    /// (Prim's-like bushy branching). See the module docs for details.
+    fn growing_tree(&mut self, rng: &mut MazeRng, corridor_bias: u8) {
+        // Fixed-size frontier (max 20x20 = 400 cells)
+        let mut frontier = [(0usize, 0usize); 400];
+        let mut frontier_len = 0;
+
+        // Start at (0, 0)
+        let mut start_cell = self.cells.get(0, 0);
+        start_cell.visited = true;
+        self.cells.set(0, 0, start_cell);
+        frontier[frontier_len] = (0, 0);
+        frontier_len += 1;
+
+        while frontier_len > 0 {
+            // Pick the newest frontier cell or a uniformly random one,
+            // weighted by corridor_bias
+            let idx = if rng.randint(0, 255) < corridor_bias as usize {
+                frontier_len - 1
+            } else {
+                rng.choice_index(frontier_len)
+            };
+
+            let (row, col) = frontier[idx];
             let (neighbors, neighbor_count) = self.get_unvisited_neighbors(row, col);
 
             if neighbor_count > 0 {
                 // Choose random unvisited neighbor
-                let idx = rng.choice_index(neighbor_count);
-                let (dir, nr, nc) = neighbors[idx];
+                let nidx = rng.choice_index(neighbor_count);
+                let (dir, nr, nc) = neighbors[nidx];
+
+                // Remove walls between the chosen cell and neighbor
+                self.open_wall(row, col, dir, nr, nc);
+
+                // Mark neighbor as visited and push it onto the frontier
+                let mut neighbor = self.cells.get(nr, nc);
+                neighbor.visited = true;
+                self.cells.set(nr, nc, neighbor);
+                frontier[frontier_len] = (nr, nc);
+                frontier_len += 1;
+            } else {
+                // The chosen cell is exhausted - swap-remove it from the
+                // frontier. Order only matters for the "newest" selection
+                // above, so swapping in the last element is safe.
+                frontier_len -= 1;
+                frontier[idx] = frontier[frontier_len];
+            }
+        }
+    }
 
-                // Remove walls between current cell and neighbor
-                self.cells[row][col].walls[dir] = false;
-                self.cells[nr][nc].walls[Self::opposite_dir(dir)] = false;
+    /// Carve extra passages at dead ends to introduce loops (braiding)
+    ///
+    /// A dead end is a cell with exactly one open passage among its four
+    /// neighbors. For each dead end, draws `rng.randint(0, 255)` and, if
+    /// the draw is below `braid_factor`, opens the wall to a random
+    /// in-bounds walled neighbor (never the outer border), creating a
+    /// cycle. Uses the same RNG stream as carving so the result stays
+    /// deterministic for a given seed.
+    fn braid(&mut self, rng: &mut MazeRng, braid_factor: u8) {
+        for row in 0..self.rows() {
+            for col in 0..self.cols() {
+                let open_count = self.cells.get(row, col)
+                    .walls
+                    .iter()
+                    .filter(|&&wall| !wall)
+                    .count();
+
+                if open_count != 1 {
+                    continue;
+                }
 
-                // Mark neighbor as visited and push to stack
-                self.cells[nr][nc].visited = true;
-                stack[stack_len] = (nr, nc);
-                stack_len += 1;
+                if rng.randint(0, 255) >= braid_factor as usize {
+                    continue;
+                }
 
-                // Move current to the neighbor (matches Python's current = next_cell)
-                current = (nr, nc);
-            } else {
-                // No unvisited neighbors, backtrack (pop)
-                // Python does: current = stack.pop()
-                // The pop() returns the element AND removes it, so current gets the POPPED value
-                // This means current stays pointing to the cell we're backtracking from!
-                // Next iteration checks that cell again (no neighbors), then pops again
-
-                // Get the value we're about to pop (which is current)
-                let popped = stack[stack_len - 1];
-                // Remove it from stack
-                stack_len -= 1;
-                // Assign popped value to current (which was already current, so it stays the same)
-                current = popped;
-
-                // Result: current still points to the same cell, stack is one shorter
-                // Next iteration will check this cell for neighbors again (finds none), then backtrack again
+                // Collect in-bounds walled neighbors (never the outer border)
+                let mut candidates = [(0usize, 0usize, 0usize); 4];
+                let mut candidate_count = 0;
+
+                let directions = [
+                    (NORTH, row.wrapping_sub(1), col),
+                    (EAST, row, col + 1),
+                    (SOUTH, row + 1, col),
+                    (WEST, row, col.wrapping_sub(1)),
+                ];
+
+                for (dir, nr, nc) in directions {
+                    if nr < self.rows() && nc < self.cols() && self.cells.get(row, col).walls[dir] {
+                        candidates[candidate_count] = (dir, nr, nc);
+                        candidate_count += 1;
+                    }
+                }
+
+                if candidate_count == 0 {
+                    continue;
+                }
+
+                let idx = rng.choice_index(candidate_count);
+                let (dir, nr, nc) = candidates[idx];
+
+                self.open_wall(row, col, dir, nr, nc);
             }
         }
     }
@@ -164,8 +385,8 @@ impl Maze {
 
         for (dir, nr, nc) in directions {
             // Check bounds (wrapping_sub returns large number if underflow)
-            if nr < self.rows && nc < self.cols {
-                if !self.cells[nr][nc].visited {
+            if nr < self.rows() && nc < self.cols() {
+                if !self.cells.get(nr, nc).visited {
                     neighbors[count] = (dir, nr, nc);
                     count += 1;
                 }
@@ -202,9 +423,9 @@ impl Maze {
     pub fn to_binary_grid(&self) -> [[u8; MAX_GRID_SIZE]; MAX_GRID_SIZE] {
         let mut grid = [[0u8; MAX_GRID_SIZE]; MAX_GRID_SIZE];
 
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                let cell = &self.cells[row][col];
+        for row in 0..self.rows() {
+            for col in 0..self.cols() {
+                let cell = self.cells.get(row, col);
 
                 // Cell center position in grid
                 let gr = row * 2 + 1;
@@ -238,15 +459,34 @@ impl Maze {
     /// on the host side. Same algorithm as to_binary_grid().
     #[cfg(feature = "std")]
     pub fn to_binary_grid_vec(&self) -> Vec<Vec<u8>> {
-        let grid_size = self.rows * 2 + 1;
+        let grid_rows = self.rows() * 2 + 1;
+        let grid_cols = self.cols() * 2 + 1;
         let grid_array = self.to_binary_grid();
 
         // Convert fixed array to Vec for JSON serialization
-        grid_array[..grid_size]
+        grid_array[..grid_rows]
             .iter()
-            .map(|row| row[..grid_size].to_vec())
+            .map(|row| row[..grid_cols].to_vec())
             .collect()
     }
+
+    /// Commit this maze's binary grid to a [`crate::MerkleGrid`], pushing
+    /// every cell in the same row-major order [`Maze::to_binary_grid`]
+    /// produces it in, so a guest can open visited cells against the
+    /// resulting root instead of re-hashing the whole grid.
+    pub fn merkle_grid(&self) -> crate::MerkleGrid {
+        let grid_rows = self.rows() * 2 + 1;
+        let grid_cols = self.cols() * 2 + 1;
+        let grid = self.to_binary_grid();
+
+        let mut merkle = crate::MerkleGrid::new(grid_cols);
+        for row in grid.iter().take(grid_rows) {
+            for &value in row.iter().take(grid_cols) {
+                merkle.push(value);
+            }
+        }
+        merkle
+    }
 }
 
 #[cfg(test)]
@@ -293,6 +533,76 @@ mod tests {
         assert_ne!(grid1, grid2);
     }
 
+    #[test]
+    fn test_generate_with_backend_determinism() {
+        use crate::rng::RngBackend;
+
+        for backend in [RngBackend::Minstd, RngBackend::Pcg32, RngBackend::Chacha8] {
+            let maze1 = Maze::generate_with_backend(10, 10, 99999, backend);
+            let maze2 = Maze::generate_with_backend(10, 10, 99999, backend);
+            assert_eq!(maze1.to_binary_grid(), maze2.to_binary_grid());
+        }
+    }
+
+    #[test]
+    fn test_braid_factor_zero_matches_perfect_maze() {
+        let perfect = Maze::generate(10, 10, 42);
+        let braided_zero = Maze::generate_with_options(10, 10, 42, RngBackend::Minstd, 0);
+        assert_eq!(perfect.to_binary_grid(), braided_zero.to_binary_grid());
+    }
+
+    #[test]
+    fn test_braid_factor_max_opens_loops() {
+        // A braid_factor of 255 should draw true for almost every dead end,
+        // so the braided grid should have strictly more open passages than
+        // the perfect maze it was carved from.
+        let perfect = Maze::generate(10, 10, 42);
+        let braided = Maze::generate_with_options(10, 10, 42, RngBackend::Minstd, 255);
+
+        let count_open = |grid: &[[u8; 41]; 41]| -> usize {
+            grid.iter().flat_map(|row| row.iter()).filter(|&&c| c == 1).count()
+        };
+
+        assert!(count_open(&braided.to_binary_grid()) > count_open(&perfect.to_binary_grid()));
+    }
+
+    #[test]
+    fn test_corridor_bias_determinism() {
+        let maze1 = Maze::generate_with_corridor_bias(10, 10, 2024, RngBackend::Minstd, 0, 64);
+        let maze2 = Maze::generate_with_corridor_bias(10, 10, 2024, RngBackend::Minstd, 0, 64);
+        assert_eq!(maze1.to_binary_grid(), maze2.to_binary_grid());
+    }
+
+    #[test]
+    fn test_corridor_bias_extremes_differ() {
+        // Mostly depth-first vs fully random-frontier selection should
+        // (almost certainly) carve different mazes from the same seed.
+        let corridors = Maze::generate_with_corridor_bias(10, 10, 2024, RngBackend::Minstd, 0, 255);
+        let bushy = Maze::generate_with_corridor_bias(10, 10, 2024, RngBackend::Minstd, 0, 0);
+        assert_ne!(corridors.to_binary_grid(), bushy.to_binary_grid());
+    }
+
+    #[test]
+    fn test_select_start_and_goal_distinct_and_in_bounds() {
+        let (maze, mut rng) = Maze::generate_with_rng(10, 10, 2918957128, RngBackend::Minstd, 0);
+        let (sr, sc, gr, gc) = maze.select_start_and_goal(&mut rng);
+
+        assert!(sr < 10 && sc < 10, "start ({}, {}) out of bounds", sr, sc);
+        assert!(gr < 10 && gc < 10, "goal ({}, {}) out of bounds", gr, gc);
+        assert_ne!((sr, sc), (gr, gc), "start and goal must not coincide");
+    }
+
+    #[test]
+    fn test_select_start_and_goal_determinism() {
+        let (maze1, mut rng1) = Maze::generate_with_rng(10, 10, 42, RngBackend::Minstd, 0);
+        let (maze2, mut rng2) = Maze::generate_with_rng(10, 10, 42, RngBackend::Minstd, 0);
+
+        assert_eq!(
+            maze1.select_start_and_goal(&mut rng1),
+            maze2.select_start_and_goal(&mut rng2)
+        );
+    }
+
     #[test]
     fn test_grid_size_20x20() {
         let maze = Maze::generate(20, 20, 2918957128);
@@ -313,6 +623,25 @@ mod tests {
         assert_eq!(grid[39][39], 1);
     }
 
+    #[test]
+    fn test_merkle_grid_matches_binary_grid() {
+        use crate::verify_grid_inclusion;
+
+        let maze = Maze::generate(5, 5, 12345);
+        let grid = maze.to_binary_grid();
+        let grid_cols = maze.cols() * 2 + 1;
+
+        let merkle = maze.merkle_grid();
+        let root = merkle.merkle_root();
+
+        for row in 0..maze.rows() * 2 + 1 {
+            for col in 0..grid_cols {
+                let proof = merkle.inclusion_proof(row, col);
+                assert!(verify_grid_inclusion(root, row, col, grid_cols, grid[row][col], &proof));
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_to_binary_grid_vec() {
@@ -11,6 +11,9 @@
 
 #![allow(dead_code)]
 
+use rand_chacha::ChaCha8Rng;
+use rand_core::{RngCore, SeedableRng};
+
 /// Park-Miller Linear Congruential Generator
 ///
 /// Generates a deterministic sequence of pseudo-random numbers from a seed.
@@ -69,6 +72,251 @@ impl SimpleLCG {
     }
 }
 
+impl RngCore for SimpleLCG {
+    fn next_u32(&mut self) -> u32 {
+        self.advance();
+        self.state
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next_u32() as u64;
+        let lo = self.next_u32() as u64;
+        (hi << 32) | lo
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        // No native byte stream, so we derive bytes by repeatedly advancing
+        // the state and taking its little-endian representation.
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            self.advance();
+            chunk.copy_from_slice(&self.state.to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            self.advance();
+            let bytes = self.state.to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for SimpleLCG {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u32::from_le_bytes(seed))
+    }
+}
+
+/// PCG32 generator (XSH-RR output function over a 64-bit LCG state)
+///
+/// Must stay deterministic and `no_std` so the guest and host agree on the
+/// exact same stream for a given seed. See O'Neill's "PCG: A Family of
+/// Simple Fast Space-Efficient Statistically Good Algorithms for Random
+/// Number Generation" for the XSH-RR permutation this implements.
+pub struct Pcg32 {
+    state: u64,
+    inc: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6364136223846793005;
+
+    /// Create a new PCG32 generator from a 32-bit seed
+    ///
+    /// The seed is widened into the 64-bit state and a fixed odd increment
+    /// is derived from it so every seed produces a distinct, full-period
+    /// stream.
+    pub fn new(seed: u32) -> Self {
+        let mut rng = Self {
+            state: 0,
+            inc: (seed as u64).wrapping_shl(1) | 1,
+        };
+        rng.state = rng
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(rng.inc);
+        rng.state = rng.state.wrapping_add(seed as u64);
+        rng.state = rng
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(rng.inc);
+        rng
+    }
+
+    /// Advance the LCG state and permute it through XSH-RR
+    fn next(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.inc);
+
+        // XSH: xorshift the high bits down into the low bits
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        // RR: rotate right by the top 5 bits of the old state
+        let rot = (old_state >> 59) as u32;
+        xorshifted.rotate_right(rot)
+    }
+}
+
+impl RngCore for Pcg32 {
+    fn next_u32(&mut self) -> u32 {
+        self.next()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let hi = self.next() as u64;
+        let lo = self.next() as u64;
+        (hi << 32) | lo
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.next().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Pcg32 {
+    type Seed = [u8; 4];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u32::from_le_bytes(seed))
+    }
+}
+
+/// Identifies which `MazeRng` backend generated a maze
+///
+/// Committed into `MazeJournal` so verification regenerates the maze with
+/// the exact same generator the prover used.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RngBackend {
+    /// Park-Miller MINSTD (`SimpleLCG`) - default, matches the Python reference
+    Minstd = 0,
+    /// PCG32 (XSH-RR permuted LCG) - higher statistical quality
+    Pcg32 = 1,
+    /// ChaCha8 stream cipher - cryptographically strong, safe for untrusted seeds
+    Chacha8 = 2,
+}
+
+impl RngBackend {
+    /// Decode a backend id committed into a journal, defaulting to `Minstd`
+    /// for forward compatibility with older journals that predate this byte.
+    pub fn from_u8(id: u8) -> Self {
+        match id {
+            1 => RngBackend::Pcg32,
+            2 => RngBackend::Chacha8,
+            _ => RngBackend::Minstd,
+        }
+    }
+}
+
+/// Pluggable RNG backend for maze generation
+///
+/// All variants are deterministic for a given seed and stay `no_std`, so the
+/// guest and host always agree on the generated sequence.
+pub enum MazeRng {
+    Minstd(SimpleLCG),
+    Pcg32(Pcg32),
+    Chacha8(ChaCha8Rng),
+}
+
+impl MazeRng {
+    /// Construct the selected backend from a 32-bit maze seed
+    pub fn new(backend: RngBackend, seed: u32) -> Self {
+        match backend {
+            RngBackend::Minstd => MazeRng::Minstd(SimpleLCG::new(seed)),
+            RngBackend::Pcg32 => MazeRng::Pcg32(Pcg32::new(seed)),
+            RngBackend::Chacha8 => MazeRng::Chacha8(ChaCha8Rng::seed_from_u64(seed as u64)),
+        }
+    }
+
+    /// The backend id to commit into `MazeJournal`
+    pub fn backend(&self) -> RngBackend {
+        match self {
+            MazeRng::Minstd(_) => RngBackend::Minstd,
+            MazeRng::Pcg32(_) => RngBackend::Pcg32,
+            MazeRng::Chacha8(_) => RngBackend::Chacha8,
+        }
+    }
+
+    /// Generate random integer in range [a, b] (inclusive)
+    ///
+    /// `SimpleLCG` keeps its own bit-exact Park-Miller implementation for
+    /// Python compatibility; the other backends derive the same ranged
+    /// output from their `RngCore::next_u32` stream.
+    pub fn randint(&mut self, a: usize, b: usize) -> usize {
+        if let MazeRng::Minstd(lcg) = self {
+            return lcg.randint(a, b);
+        }
+
+        const M: u64 = u32::MAX as u64 + 1;
+        let range = (b - a + 1) as u64;
+        let scaled = (self.next_u32() as u64 * range) / M;
+        a + scaled as usize
+    }
+
+    /// Choose random index from a range [0, len)
+    pub fn choice_index(&mut self, len: usize) -> usize {
+        if let MazeRng::Minstd(lcg) = self {
+            return lcg.choice_index(len);
+        }
+
+        const M: u64 = u32::MAX as u64 + 1;
+        let scaled = (self.next_u32() as u64 * len as u64) / M;
+        scaled as usize
+    }
+}
+
+impl RngCore for MazeRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            MazeRng::Minstd(lcg) => lcg.next_u32(),
+            MazeRng::Pcg32(pcg) => pcg.next_u32(),
+            MazeRng::Chacha8(chacha) => chacha.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            MazeRng::Minstd(lcg) => lcg.next_u64(),
+            MazeRng::Pcg32(pcg) => pcg.next_u64(),
+            MazeRng::Chacha8(chacha) => chacha.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            MazeRng::Minstd(lcg) => lcg.fill_bytes(dest),
+            MazeRng::Pcg32(pcg) => pcg.fill_bytes(dest),
+            MazeRng::Chacha8(chacha) => chacha.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +375,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pcg32_determinism() {
+        let mut rng1 = Pcg32::new(12345);
+        let mut rng2 = Pcg32::new(12345);
+
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn test_maze_rng_backend_roundtrip() {
+        for backend in [RngBackend::Minstd, RngBackend::Pcg32, RngBackend::Chacha8] {
+            let mut rng = MazeRng::new(backend, 2918957128);
+            assert_eq!(rng.backend(), backend);
+
+            for _ in 0..50 {
+                let val = rng.choice_index(7);
+                assert!(val < 7, "choice_index {} out of range [0, 7)", val);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rng_backend_from_u8() {
+        assert_eq!(RngBackend::from_u8(0), RngBackend::Minstd);
+        assert_eq!(RngBackend::from_u8(1), RngBackend::Pcg32);
+        assert_eq!(RngBackend::from_u8(2), RngBackend::Chacha8);
+        assert_eq!(RngBackend::from_u8(99), RngBackend::Minstd);
+    }
+
     #[test]
     fn test_known_sequence() {
         // Test that same seed produces consistent sequence
@@ -0,0 +1,117 @@
+//! Minimal Base58 (Bitcoin alphabet) codec, used by [`crate::PathProof::share`]
+//! to turn a packed proof into a copy-paste-able token.
+
+use std::fmt;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Error returned by [`decode`] when the input isn't valid Base58
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Base58Error {
+    /// A byte in the input string isn't part of the Base58 alphabet
+    InvalidCharacter(u8),
+}
+
+impl fmt::Display for Base58Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base58Error::InvalidCharacter(byte) => {
+                write!(f, "invalid character: byte 0x{:02x} is not valid Base58", byte)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Base58Error {}
+
+/// Encode `data` as a Base58 string, preserving leading zero bytes as
+/// leading '1' characters the same way Bitcoin's Base58Check does.
+pub fn encode(data: &[u8]) -> String {
+    let zero_count = data.iter().take_while(|&&b| b == 0).count();
+
+    // Base 256 -> base 58 via repeated division, digits come out
+    // little-endian and get reversed at the end.
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in data {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut encoded: String = "1".repeat(zero_count);
+    encoded.extend(digits.iter().rev().map(|&d| ALPHABET[d as usize] as char));
+    encoded
+}
+
+/// Decode a Base58 string back into bytes.
+pub fn decode(s: &str) -> Result<Vec<u8>, Base58Error> {
+    let zero_count = s.bytes().take_while(|&b| b == b'1').count();
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    for byte in s.bytes() {
+        let digit = ALPHABET
+            .iter()
+            .position(|&c| c == byte)
+            .ok_or(Base58Error::InvalidCharacter(byte))? as u32;
+
+        let mut carry = digit;
+        for b in bytes.iter_mut() {
+            carry += (*b as u32) * 58;
+            *b = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut decoded = vec![0u8; zero_count];
+    decoded.extend(bytes.iter().rev());
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        let data = b"a packed proof's worth of arbitrary bytes, including \x00\x01\xff";
+        assert_eq!(decode(&encode(data)).unwrap(), data);
+    }
+
+    #[test]
+    fn test_round_trip_preserves_leading_zeros() {
+        let data = [0u8, 0, 0, 1, 2, 3];
+        let encoded = encode(&data);
+        assert!(encoded.starts_with("111"), "leading zero bytes should become leading '1's, got {encoded}");
+        assert_eq!(decode(&encoded).unwrap(), data);
+    }
+
+    #[test]
+    fn test_round_trip_empty() {
+        assert_eq!(encode(&[]), "");
+        assert_eq!(decode("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        // '0', 'O', 'I', 'l' are deliberately excluded from the Base58 alphabet.
+        assert_eq!(decode("0"), Err(Base58Error::InvalidCharacter(b'0')));
+    }
+
+    #[test]
+    fn test_known_vector() {
+        // "Hello World" is a standard Base58 test vector.
+        assert_eq!(encode(b"Hello World"), "JxF12TrwUP45BMd");
+        assert_eq!(decode("JxF12TrwUP45BMd").unwrap(), b"Hello World");
+    }
+}
@@ -0,0 +1,65 @@
+//! Proving-time statistics for the CLI's `bench` mode.
+//!
+//! A single `Instant`-measured proving run is noisy - machine load, guest
+//! cache warmth, and scheduling jitter all move it around. [`ConfidenceInterval`]
+//! turns a sample of repeated runs into a mean and a proper two-sided 95%
+//! confidence interval, so `bench generate-maze` can report e.g.
+//! "groth16: 4.812s ± 0.203s (95% CI, n=10)" instead of one run's raw number.
+
+use std::time::Duration;
+
+/// A sample mean with its two-sided 95% confidence margin, in seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfidenceInterval {
+    pub mean_secs: f64,
+    pub margin_secs: f64,
+    pub runs: usize,
+}
+
+impl ConfidenceInterval {
+    /// Build a 95% CI from a sample of per-run durations: `mean ± t *
+    /// stddev / sqrt(n)`, using Student's t critical value for `n - 1`
+    /// degrees of freedom (falling back to the normal z-critical value once
+    /// `n` is large enough that the two are indistinguishable).
+    ///
+    /// Returns `None` for an empty sample. A single-run sample has no
+    /// variance to measure, so its margin is reported as zero rather than
+    /// `None`.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let n = samples.len();
+        if n == 0 {
+            return None;
+        }
+
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+
+        if n == 1 {
+            return Some(ConfidenceInterval { mean_secs: mean, margin_secs: 0.0, runs: n });
+        }
+
+        let variance = secs.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        let stddev = variance.sqrt();
+        let margin = t_critical_95(n - 1) * stddev / (n as f64).sqrt();
+
+        Some(ConfidenceInterval { mean_secs: mean, margin_secs: margin, runs: n })
+    }
+}
+
+/// Two-sided 95% critical value for Student's t distribution at `df`
+/// degrees of freedom. Tabulated for `df` 1-30, where t diverges noticeably
+/// from the normal distribution; falls back to the normal z-critical value
+/// (1.96) beyond that, where the two are close enough not to matter for a
+/// benchmark report.
+fn t_critical_95(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+        2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+        2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+    ];
+
+    match df {
+        1..=30 => TABLE[df - 1],
+        _ => 1.96,
+    }
+}
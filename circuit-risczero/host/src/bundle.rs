@@ -0,0 +1,386 @@
+//! Self-describing, versioned proof containers ([`MazeProofBundle`]/
+//! [`PathProofBundle`]) that carry the guest image ID a receipt was produced
+//! against, instead of relying on whichever `MAZE_GEN_ID`/`PATH_VERIFY_ID`
+//! happens to be compiled into the tool that loads it.
+//!
+//! A bare [`Receipt`] only verifies against whatever image ID the *caller*
+//! supplies - nothing in the blob itself says which guest produced it, so a
+//! proof saved by one build of this crate can be checked against the wrong
+//! guest by a differently-versioned tool without either side noticing.
+//! [`MazeProofBundle::verify_bundle`]/[`PathProofBundle::verify_bundle`]
+//! close that hole by rejecting an image ID mismatch explicitly, before ever
+//! calling `receipt.verify`.
+//!
+//! `MazeProofBundle` additionally carries `grid_data`/`grid_hash`, so a
+//! verifier can resume path verification directly from a loaded bundle
+//! instead of calling `regenerate_maze_grid` again.
+
+use std::fs;
+use std::path::Path;
+
+use risc0_zkvm::sha::{Impl as SHA256, Sha256};
+use risc0_zkvm::Receipt;
+use serde::{Deserialize, Serialize};
+
+use maze_core::{MAZE_JOURNAL_SIZE, NULLIFIER_JOURNAL_SIZE};
+
+use crate::{expected_maze_gen_image_id, expected_path_verify_image_id, MazeProof, PathProof, ReceiptKind};
+
+/// Format version of [`MazeProofBundle`]/[`PathProofBundle`]'s on-disk
+/// layout. Bump whenever a field is added, removed, or reinterpreted.
+const BUNDLE_VERSION: u8 = 1;
+
+/// Convert a `[u32; 8]` risc0 image ID (e.g. `MAZE_GEN_ID`/`PATH_VERIFY_ID`)
+/// to the `[u8; 32]` digest bytes a bundle stores - same byte order
+/// [`crate::expected_path_verify_image_id`] builds its `Digest` from.
+fn image_id_to_bytes(image_id: [u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, &word) in image_id.iter().enumerate() {
+        bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+/// Portable container for a [`MazeProof`]'s receipt: the `MAZE_GEN_ID` image
+/// ID it was produced against, the grid data/hash needed to resume path
+/// verification without calling `regenerate_maze_grid` again, and a CRC32
+/// over the payload to catch truncation or bit-rot in storage/transport.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MazeProofBundle {
+    version: u8,
+    image_id: [u8; 32],
+    receipt_kind: ReceiptKind,
+    maze_seed: u32,
+    grid_hash: [u8; 32],
+    grid_data: Vec<Vec<u8>>,
+    receipt: Receipt,
+    crc: u32,
+}
+
+impl MazeProofBundle {
+    fn crc(
+        version: u8,
+        image_id: &[u8; 32],
+        receipt_kind: ReceiptKind,
+        maze_seed: u32,
+        grid_hash: &[u8; 32],
+        grid_data: &[Vec<u8>],
+        receipt_bytes: &[u8],
+    ) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&[version]);
+        hasher.update(image_id);
+        hasher.update(&[receipt_kind.as_tag()]);
+        hasher.update(&maze_seed.to_le_bytes());
+        hasher.update(grid_hash);
+        for row in grid_data {
+            hasher.update(row);
+        }
+        hasher.update(receipt_bytes);
+        hasher.finalize()
+    }
+
+    /// Wrap `maze_proof` into a bundle, embedding this build's own
+    /// `MAZE_GEN_ID` - the image ID the receipt was actually produced
+    /// against - and computing its CRC32 over the whole payload.
+    pub fn from_maze_proof(maze_proof: &MazeProof) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut image_id = [0u8; 32];
+        image_id.copy_from_slice(expected_maze_gen_image_id().as_bytes());
+        let receipt_bytes = serde_json::to_vec(&maze_proof.receipt)?;
+        let crc = Self::crc(
+            BUNDLE_VERSION,
+            &image_id,
+            maze_proof.receipt_kind,
+            maze_proof.maze_seed,
+            &maze_proof.grid_hash,
+            &maze_proof.grid_data,
+            &receipt_bytes,
+        );
+
+        Ok(Self {
+            version: BUNDLE_VERSION,
+            image_id,
+            receipt_kind: maze_proof.receipt_kind,
+            maze_seed: maze_proof.maze_seed,
+            grid_hash: maze_proof.grid_hash,
+            grid_data: maze_proof.grid_data.clone(),
+            receipt: maze_proof.receipt.clone(),
+            crc,
+        })
+    }
+
+    /// Serialize this bundle to `path` as MessagePack, matching the repo's
+    /// established `rmp_serde`-based proof storage convention.
+    pub fn save_bundle(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, rmp_serde::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// Load a bundle saved by [`MazeProofBundle::save_bundle`], rejecting it
+    /// if its format version is unsupported, its CRC32 doesn't match its
+    /// payload, or `SHA-256(grid_data) != grid_hash` - any of which mean the
+    /// bundle was truncated, corrupted, or tampered with in storage.
+    pub fn load_bundle(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let bytes = fs::read(path)?;
+        let bundle: Self = rmp_serde::from_slice(&bytes)?;
+
+        if bundle.version != BUNDLE_VERSION {
+            return Err(format!("Unsupported maze proof bundle version: {}", bundle.version).into());
+        }
+
+        let receipt_bytes = serde_json::to_vec(&bundle.receipt)?;
+        let expected_crc = Self::crc(
+            bundle.version,
+            &bundle.image_id,
+            bundle.receipt_kind,
+            bundle.maze_seed,
+            &bundle.grid_hash,
+            &bundle.grid_data,
+            &receipt_bytes,
+        );
+        if expected_crc != bundle.crc {
+            return Err(format!(
+                "Maze proof bundle failed its CRC32 check: expected {:08x}, got {:08x}",
+                expected_crc, bundle.crc
+            )
+            .into());
+        }
+
+        let mut grid_flat = Vec::with_capacity(bundle.grid_data.iter().map(Vec::len).sum());
+        for row in &bundle.grid_data {
+            grid_flat.extend_from_slice(row);
+        }
+        let mut computed_hash = [0u8; 32];
+        computed_hash.copy_from_slice(SHA256::hash_bytes(&grid_flat).as_bytes());
+        if computed_hash != bundle.grid_hash {
+            return Err("Maze proof bundle's grid data does not hash to its committed grid_hash".into());
+        }
+
+        Ok(bundle)
+    }
+
+    /// Check `expected_image_id` (the caller's own `MAZE_GEN_ID`) against the
+    /// image ID embedded in this bundle, rejecting a mismatch explicitly,
+    /// then cryptographically verify the receipt against it.
+    pub fn verify_bundle(&self, expected_image_id: [u32; 8]) -> Result<(), Box<dyn std::error::Error>> {
+        let expected_bytes = image_id_to_bytes(expected_image_id);
+        if expected_bytes != self.image_id {
+            return Err(format!(
+                "Bundle was produced against a different maze-gen image ID than expected (embedded {:02x?}..., expected {:02x?}...)",
+                &self.image_id[..4],
+                &expected_bytes[..4]
+            )
+            .into());
+        }
+
+        self.receipt
+            .verify(expected_image_id)
+            .map_err(|e| format!("Receipt verification failed: {}", e))?;
+        Ok(())
+    }
+
+    /// Reconstruct the full [`MazeProof`] this bundle was built from,
+    /// re-deriving the journal fields and attaching the bundle's own
+    /// `grid_data` - the point of carrying it, instead of calling
+    /// `regenerate_maze_grid` to get it back.
+    pub fn into_maze_proof(self) -> Result<MazeProof, Box<dyn std::error::Error>> {
+        let journal_bytes = &self.receipt.journal.bytes;
+        if journal_bytes.len() < MAZE_JOURNAL_SIZE {
+            return Err(format!(
+                "Journal too short: expected {} bytes, got {}",
+                MAZE_JOURNAL_SIZE,
+                journal_bytes.len()
+            )
+            .into());
+        }
+
+        let maze_seed = u32::from_le_bytes([journal_bytes[0], journal_bytes[1], journal_bytes[2], journal_bytes[3]]);
+
+        let mut grid_hash = [0u8; 32];
+        grid_hash.copy_from_slice(&journal_bytes[4..4 + 32]);
+
+        let rng_backend = journal_bytes[4 + 32];
+        let braid_factor = journal_bytes[4 + 32 + 1];
+        let start_row = journal_bytes[4 + 32 + 2];
+        let start_col = journal_bytes[4 + 32 + 3];
+        let goal_row = journal_bytes[4 + 32 + 4];
+        let goal_col = journal_bytes[4 + 32 + 5];
+        let corridor_bias = journal_bytes[4 + 32 + 6];
+        let rows = journal_bytes[4 + 32 + 7];
+        let cols = journal_bytes[4 + 32 + 8];
+
+        Ok(MazeProof {
+            maze_seed,
+            grid_hash,
+            rng_backend,
+            braid_factor,
+            start_row,
+            start_col,
+            goal_row,
+            goal_col,
+            corridor_bias,
+            rows,
+            cols,
+            grid_data: self.grid_data,
+            receipt: self.receipt,
+            receipt_kind: self.receipt_kind,
+        })
+    }
+}
+
+/// Portable container for a [`PathProof`]'s receipt: the `PATH_VERIFY_ID`
+/// image ID it was produced against, plus the same CRC32 integrity check as
+/// [`MazeProofBundle`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathProofBundle {
+    version: u8,
+    image_id: [u8; 32],
+    receipt_kind: ReceiptKind,
+    maze_seed: u32,
+    grid_hash: [u8; 32],
+    receipt: Receipt,
+    crc: u32,
+}
+
+impl PathProofBundle {
+    fn crc(
+        version: u8,
+        image_id: &[u8; 32],
+        receipt_kind: ReceiptKind,
+        maze_seed: u32,
+        grid_hash: &[u8; 32],
+        receipt_bytes: &[u8],
+    ) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(&[version]);
+        hasher.update(image_id);
+        hasher.update(&[receipt_kind.as_tag()]);
+        hasher.update(&maze_seed.to_le_bytes());
+        hasher.update(grid_hash);
+        hasher.update(receipt_bytes);
+        hasher.finalize()
+    }
+
+    /// Wrap `path_proof` into a bundle, embedding this build's own
+    /// `PATH_VERIFY_ID` and computing its CRC32 over the whole payload.
+    pub fn from_path_proof(path_proof: &PathProof) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut image_id = [0u8; 32];
+        image_id.copy_from_slice(expected_path_verify_image_id().as_bytes());
+        let receipt_bytes = serde_json::to_vec(&path_proof.receipt)?;
+        let crc = Self::crc(
+            BUNDLE_VERSION,
+            &image_id,
+            path_proof.receipt_kind,
+            path_proof.maze_seed,
+            &path_proof.grid_hash,
+            &receipt_bytes,
+        );
+
+        Ok(Self {
+            version: BUNDLE_VERSION,
+            image_id,
+            receipt_kind: path_proof.receipt_kind,
+            maze_seed: path_proof.maze_seed,
+            grid_hash: path_proof.grid_hash,
+            receipt: path_proof.receipt.clone(),
+            crc,
+        })
+    }
+
+    /// Serialize this bundle to `path` as MessagePack, matching
+    /// [`MazeProofBundle::save_bundle`].
+    pub fn save_bundle(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, rmp_serde::to_vec(self)?)?;
+        Ok(())
+    }
+
+    /// Load a bundle saved by [`PathProofBundle::save_bundle`], rejecting it
+    /// if its format version is unsupported or its CRC32 doesn't match its
+    /// payload.
+    pub fn load_bundle(path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let bytes = fs::read(path)?;
+        let bundle: Self = rmp_serde::from_slice(&bytes)?;
+
+        if bundle.version != BUNDLE_VERSION {
+            return Err(format!("Unsupported path proof bundle version: {}", bundle.version).into());
+        }
+
+        let receipt_bytes = serde_json::to_vec(&bundle.receipt)?;
+        let expected_crc = Self::crc(
+            bundle.version,
+            &bundle.image_id,
+            bundle.receipt_kind,
+            bundle.maze_seed,
+            &bundle.grid_hash,
+            &receipt_bytes,
+        );
+        if expected_crc != bundle.crc {
+            return Err(format!(
+                "Path proof bundle failed its CRC32 check: expected {:08x}, got {:08x}",
+                expected_crc, bundle.crc
+            )
+            .into());
+        }
+
+        Ok(bundle)
+    }
+
+    /// Check `expected_image_id` (the caller's own `PATH_VERIFY_ID`) against
+    /// the image ID embedded in this bundle, rejecting a mismatch
+    /// explicitly, then cryptographically verify the receipt against it.
+    pub fn verify_bundle(&self, expected_image_id: [u32; 8]) -> Result<(), Box<dyn std::error::Error>> {
+        let expected_bytes = image_id_to_bytes(expected_image_id);
+        if expected_bytes != self.image_id {
+            return Err(format!(
+                "Bundle was produced against a different path-verify image ID than expected (embedded {:02x?}..., expected {:02x?}...)",
+                &self.image_id[..4],
+                &expected_bytes[..4]
+            )
+            .into());
+        }
+
+        self.receipt
+            .verify(expected_image_id)
+            .map_err(|e| format!("Receipt verification failed: {}", e))?;
+        Ok(())
+    }
+
+    /// Reconstruct the full [`PathProof`] this bundle was built from by
+    /// re-deriving `is_valid`/`maze_seed`/the nullifier extension from the
+    /// receipt's own journal, mirroring [`PathProof::unpack`]'s decode.
+    pub fn into_path_proof(self) -> Result<PathProof, Box<dyn std::error::Error>> {
+        let journal_bytes = &self.receipt.journal.bytes;
+        let expected_len = 8 + NULLIFIER_JOURNAL_SIZE;
+        if journal_bytes.len() < expected_len {
+            return Err(format!(
+                "Journal too short: expected {} bytes, got {}",
+                expected_len,
+                journal_bytes.len()
+            )
+            .into());
+        }
+
+        let is_valid = u32::from_le_bytes([journal_bytes[0], journal_bytes[1], journal_bytes[2], journal_bytes[3]]) != 0;
+        let maze_seed = u32::from_le_bytes([journal_bytes[4], journal_bytes[5], journal_bytes[6], journal_bytes[7]]);
+
+        let mut identity_root = [0u8; 32];
+        identity_root.copy_from_slice(&journal_bytes[8..8 + 32]);
+        let mut nullifier_hash = [0u8; 32];
+        nullifier_hash.copy_from_slice(&journal_bytes[8 + 32..8 + 64]);
+        let (identity_root, nullifier_hash) = if identity_root == [0u8; 32] && nullifier_hash == [0u8; 32] {
+            (None, None)
+        } else {
+            (Some(identity_root), Some(nullifier_hash))
+        };
+
+        Ok(PathProof {
+            is_valid,
+            maze_seed,
+            grid_hash: self.grid_hash,
+            identity_root,
+            nullifier_hash,
+            receipt: self.receipt,
+            receipt_kind: self.receipt_kind,
+        })
+    }
+}
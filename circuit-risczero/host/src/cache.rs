@@ -0,0 +1,110 @@
+//! Content-addressed cache for proving results.
+//!
+//! `generate_maze_proof_with_dimensions` and `verify_path_proof` re-run the
+//! zkVM on every call, even for a request that's byte-identical to one
+//! already proven. This module lets them short-circuit to a cached result
+//! keyed by a hash of their own inputs, under a `.zkp-maze-cache/` directory.
+//!
+//! Lookups use two-stage hashing the way a deduplicating file store does:
+//! a cheap hash over a fixed-size prefix of the input buckets candidates
+//! into a directory, and the full hash - needed only to disambiguate a
+//! same-prefix collision - is computed on a bucket hit, not on every call.
+
+use risc0_zkvm::sha::{Impl as SHA256, Sha256};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Number of leading input bytes hashed for the cheap bucketing pass.
+const PREFIX_LEN: usize = 4096;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enable or disable the cache for the rest of the process. Backs the CLI's
+/// `--no-cache` flag.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn root_dir() -> PathBuf {
+    PathBuf::from(".zkp-maze-cache")
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    SHA256::hash_bytes(data)
+        .as_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Bucket directory for `key`: a hash of just its first `PREFIX_LEN` bytes,
+/// so a miss on a prefix with no bucket never requires hashing a
+/// (potentially large) input in full.
+fn bucket_dir(key: &[u8]) -> PathBuf {
+    let prefix = &key[..key.len().min(PREFIX_LEN)];
+    root_dir().join(sha256_hex(prefix))
+}
+
+/// Look up a cached value for `key` (the serialized inputs identifying a
+/// proving call). `None` on a miss, a disabled cache, or a read error.
+pub(crate) fn get(key: &[u8]) -> Option<Vec<u8>> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let bucket = bucket_dir(key);
+    if !bucket.is_dir() {
+        // Nothing shares this key's prefix - skip the full hash entirely.
+        return None;
+    }
+
+    fs::read(bucket.join(sha256_hex(key))).ok()
+}
+
+/// Store `value` under `key`, creating its bucket directory if needed.
+/// Silently does nothing on a disabled cache or I/O error - a cache miss on
+/// the next call is the only consequence.
+pub(crate) fn put(key: &[u8], value: &[u8]) {
+    if !is_enabled() {
+        return;
+    }
+
+    let bucket = bucket_dir(key);
+    if fs::create_dir_all(&bucket).is_err() {
+        return;
+    }
+
+    let _ = fs::write(bucket.join(sha256_hex(key)), value);
+}
+
+/// Delete every cached entry, returning `(entries_removed, bytes_removed)`.
+/// Backs the CLI's `cache gc` subcommand.
+pub fn gc() -> io::Result<(usize, u64)> {
+    let root = root_dir();
+    if !root.is_dir() {
+        return Ok((0, 0));
+    }
+
+    let mut entries = 0usize;
+    let mut bytes = 0u64;
+    for bucket in fs::read_dir(&root)? {
+        let bucket = bucket?.path();
+        if !bucket.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&bucket)? {
+            let entry = entry?;
+            bytes += entry.metadata()?.len();
+            entries += 1;
+        }
+    }
+
+    fs::remove_dir_all(&root)?;
+    Ok((entries, bytes))
+}
@@ -0,0 +1,190 @@
+//! C ABI for embedding the prover/verifier into non-Rust game clients.
+//!
+//! Every entry point takes a MessagePack-encoded request buffer and returns
+//! an [`FfiResult`]: on success, `ok` holds a MessagePack-encoded response
+//! (or, where noted, our compact `pack`'d proof bytes); on failure, `err`
+//! holds a NUL-terminated UTF-8 message. The request shapes here are plain
+//! maps of primitives (seed, receipt kind string, raw proof bytes, move
+//! bytes) so a C, Swift, or Nim/WASM binding doesn't need to mirror the
+//! serde `Receipt` internals that the JSON/MessagePack file formats expose.
+//!
+//! Every non-null pointer returned by these functions is owned by the
+//! caller until passed to [`maze_ffi_free_buffer`] / [`maze_ffi_free_error`].
+
+use crate::{generate_maze_proof, verify_path_proof, verify_path_proof_receipt, MazeProof, PathProof, ReceiptKind};
+use serde::{Deserialize, Serialize};
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+/// A byte buffer owned by the caller, returned by a successful FFI call.
+#[repr(C)]
+pub struct FfiBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl FfiBuffer {
+    fn empty() -> Self {
+        FfiBuffer { data: std::ptr::null_mut(), len: 0 }
+    }
+
+    fn from_vec(mut bytes: Vec<u8>) -> Self {
+        bytes.shrink_to_fit();
+        let buf = FfiBuffer { data: bytes.as_mut_ptr(), len: bytes.len() };
+        std::mem::forget(bytes);
+        buf
+    }
+}
+
+/// Result of an FFI call: exactly one of `ok`/`err` is populated.
+#[repr(C)]
+pub struct FfiResult {
+    /// MessagePack-encoded response (or packed proof bytes), on success
+    pub ok: FfiBuffer,
+    /// NUL-terminated UTF-8 error message, on failure
+    pub err: *mut c_char,
+}
+
+impl FfiResult {
+    fn ok(bytes: Vec<u8>) -> Self {
+        FfiResult { ok: FfiBuffer::from_vec(bytes), err: std::ptr::null_mut() }
+    }
+
+    fn err(message: impl std::fmt::Display) -> Self {
+        let message = CString::new(message.to_string())
+            .unwrap_or_else(|_| CString::new("error message contained a NUL byte").unwrap());
+        FfiResult { ok: FfiBuffer::empty(), err: message.into_raw() }
+    }
+}
+
+/// Free a buffer returned in [`FfiResult::ok`].
+#[no_mangle]
+pub extern "C" fn maze_ffi_free_buffer(buf: FfiBuffer) {
+    if !buf.data.is_null() {
+        unsafe {
+            drop(Vec::from_raw_parts(buf.data, buf.len, buf.len));
+        }
+    }
+}
+
+/// Free an error message returned in [`FfiResult::err`].
+#[no_mangle]
+pub extern "C" fn maze_ffi_free_error(err: *mut c_char) {
+    if !err.is_null() {
+        unsafe {
+            drop(CString::from_raw(err));
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct GenerateProofRequest {
+    maze_seed: u32,
+    receipt_kind: String,
+}
+
+#[derive(Serialize)]
+struct GenerateProofResponse {
+    /// Packed [`MazeProof::pack`] bytes
+    maze_proof: Vec<u8>,
+}
+
+fn generate_proof_inner(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let request: GenerateProofRequest = rmp_serde::from_slice(input)?;
+    let receipt_kind: ReceiptKind = request.receipt_kind.parse()?;
+    let maze_proof = generate_maze_proof(request.maze_seed, receipt_kind)?;
+    let response = GenerateProofResponse { maze_proof: maze_proof.pack()? };
+    Ok(rmp_serde::to_vec(&response)?)
+}
+
+/// Generate a maze proof. Request: `{maze_seed: u32, receipt_kind: str}`.
+/// Response: `{maze_proof: bytes}` (packed [`MazeProof`]).
+///
+/// # Safety
+/// `input_ptr` must point to `input_len` valid, readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn maze_ffi_generate_proof(input_ptr: *const u8, input_len: usize) -> FfiResult {
+    let input = std::slice::from_raw_parts(input_ptr, input_len);
+    match generate_proof_inner(input) {
+        Ok(bytes) => FfiResult::ok(bytes),
+        Err(e) => FfiResult::err(e),
+    }
+}
+
+#[derive(Deserialize)]
+struct VerifyPathRequest {
+    /// Packed [`MazeProof::pack`] bytes
+    maze_proof: Vec<u8>,
+    moves: Vec<u8>,
+    receipt_kind_override: Option<String>,
+}
+
+#[derive(Serialize)]
+struct VerifyPathResponse {
+    /// Packed [`PathProof::pack`] bytes - requires the resulting receipt be Groth16
+    path_proof: Vec<u8>,
+}
+
+fn verify_path_inner(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let request: VerifyPathRequest = rmp_serde::from_slice(input)?;
+    let maze_proof = MazeProof::unpack(&request.maze_proof)?;
+    let receipt_kind_override = request
+        .receipt_kind_override
+        .map(|s| s.parse())
+        .transpose()?;
+    let path_proof = verify_path_proof(&maze_proof, request.moves, receipt_kind_override)?;
+    let response = VerifyPathResponse { path_proof: path_proof.pack()? };
+    Ok(rmp_serde::to_vec(&response)?)
+}
+
+/// Generate a path verification proof. Request:
+/// `{maze_proof: bytes, moves: [u8], receipt_kind_override: str?}`.
+/// Response: `{path_proof: bytes}` (packed [`PathProof`], so the resulting
+/// receipt must be Groth16).
+///
+/// # Safety
+/// `input_ptr` must point to `input_len` valid, readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn maze_ffi_verify_path_proof(input_ptr: *const u8, input_len: usize) -> FfiResult {
+    let input = std::slice::from_raw_parts(input_ptr, input_len);
+    match verify_path_inner(input) {
+        Ok(bytes) => FfiResult::ok(bytes),
+        Err(e) => FfiResult::err(e),
+    }
+}
+
+#[derive(Deserialize)]
+struct VerifyReceiptRequest {
+    /// Packed [`PathProof::pack`] bytes
+    path_proof: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct VerifyReceiptResponse {
+    valid: bool,
+}
+
+fn verify_receipt_inner(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let request: VerifyReceiptRequest = rmp_serde::from_slice(input)?;
+    let path_proof = PathProof::unpack(&request.path_proof)?;
+    verify_path_proof_receipt(&path_proof)?;
+    let response = VerifyReceiptResponse { valid: true };
+    Ok(rmp_serde::to_vec(&response)?)
+}
+
+/// Cryptographically verify a path proof receipt. Request:
+/// `{path_proof: bytes}`. Response: `{valid: bool}` on success; a
+/// cryptographically invalid receipt is reported via `FfiResult::err`
+/// rather than `valid: false`, matching [`verify_path_proof_receipt`]'s
+/// `Result`-based error reporting.
+///
+/// # Safety
+/// `input_ptr` must point to `input_len` valid, readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn maze_ffi_verify_path_proof_receipt(input_ptr: *const u8, input_len: usize) -> FfiResult {
+    let input = std::slice::from_raw_parts(input_ptr, input_len);
+    match verify_receipt_inner(input) {
+        Ok(bytes) => FfiResult::ok(bytes),
+        Err(e) => FfiResult::err(e),
+    }
+}
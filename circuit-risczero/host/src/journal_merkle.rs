@@ -0,0 +1,147 @@
+//! One-shot binary Merkle tree over a batch of [`crate::PathProof`] journal
+//! bytes, for `/api/prove/batch`.
+//!
+//! Unlike [`crate::mmr::MmrAccumulator`] (an append-order MMR with bagged
+//! peaks, for a commitment that keeps growing) or
+//! `maze_core::merkle_grid::MerkleGrid` (odd nodes duplicated, for streaming
+//! grid cells one at a time), this tree is built once over a fixed batch:
+//! each leaf is `SHA256(journal_bytes)`, each internal node is
+//! `SHA256(left || right)`, and an odd node at any level is promoted
+//! unchanged to the next level instead of being paired with itself. That
+//! makes the root reconstructable purely from the leaf count and order, with
+//! no padding convention to get wrong.
+
+use risc0_zkvm::sha::{Impl as SHA256, Sha256};
+use serde::{Deserialize, Serialize};
+
+pub type Hash = [u8; 32];
+
+fn hash_bytes(data: &[u8]) -> Hash {
+    let digest = SHA256::hash_bytes(data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    hash_bytes(&buf)
+}
+
+/// Leaf commitment for one journal: `SHA256(journal_bytes)`.
+pub fn hash_leaf(journal_bytes: &[u8]) -> Hash {
+    hash_bytes(journal_bytes)
+}
+
+/// Proof that one leaf is included in a [`build`] root: the sibling hashes
+/// climbing from the leaf to the root, each tagged with which side it sits
+/// on. A level where the leaf's node was promoted unchanged (no sibling)
+/// contributes no entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub leaf_index: usize,
+    /// (sibling hash, sibling is on the right) per level, bottom-up
+    pub siblings: Vec<(Hash, bool)>,
+}
+
+/// Build the tree over already-hashed `leaves`, returning the root and one
+/// inclusion proof per leaf, in the same order as the input.
+pub fn build(leaves: &[Hash]) -> (Hash, Vec<InclusionProof>) {
+    if leaves.is_empty() {
+        return ([0u8; 32], Vec::new());
+    }
+
+    let mut proofs: Vec<InclusionProof> = (0..leaves.len())
+        .map(|i| InclusionProof {
+            leaf_index: i,
+            siblings: Vec::new(),
+        })
+        .collect();
+
+    // `owners[pos]` tracks which original leaf indices currently live at
+    // position `pos` of the level being folded, so a sibling hash recorded
+    // during pairing lands in every proof it's relevant to.
+    let mut level: Vec<Hash> = leaves.to_vec();
+    let mut owners: Vec<Vec<usize>> = (0..leaves.len()).map(|i| vec![i]).collect();
+
+    while level.len() > 1 {
+        let mut next_level = Vec::with_capacity(level.len().div_ceil(2));
+        let mut next_owners: Vec<Vec<usize>> = Vec::with_capacity(next_level.capacity());
+
+        let mut i = 0;
+        while i < level.len() {
+            if i + 1 < level.len() {
+                let left = level[i];
+                let right = level[i + 1];
+
+                for &leaf_idx in &owners[i] {
+                    proofs[leaf_idx].siblings.push((right, true));
+                }
+                for &leaf_idx in &owners[i + 1] {
+                    proofs[leaf_idx].siblings.push((left, false));
+                }
+
+                next_level.push(hash_pair(&left, &right));
+                let mut combined = owners[i].clone();
+                combined.extend(owners[i + 1].clone());
+                next_owners.push(combined);
+                i += 2;
+            } else {
+                // Odd node out: promoted unchanged, no sibling to record.
+                next_level.push(level[i]);
+                next_owners.push(owners[i].clone());
+                i += 1;
+            }
+        }
+
+        level = next_level;
+        owners = next_owners;
+    }
+
+    (level[0], proofs)
+}
+
+/// Verify an [`InclusionProof`] for `leaf_hash` against a published `root`.
+pub fn verify_inclusion(leaf_hash: Hash, proof: &InclusionProof, root: Hash) -> bool {
+    let mut acc = leaf_hash;
+    for (sibling, sibling_on_right) in &proof.siblings {
+        acc = if *sibling_on_right {
+            hash_pair(&acc, sibling)
+        } else {
+            hash_pair(sibling, &acc)
+        };
+    }
+    acc == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_is_deterministic() {
+        let leaves: Vec<Hash> = (0u8..5).map(|i| hash_leaf(&[i])).collect();
+        let (root_a, _) = build(&leaves);
+        let (root_b, _) = build(&leaves);
+        assert_eq!(root_a, root_b);
+    }
+
+    #[test]
+    fn test_inclusion_proofs_round_trip_even_and_odd_batches() {
+        for batch_size in [1usize, 2, 3, 4, 5, 7, 8] {
+            let leaves: Vec<Hash> = (0..batch_size as u8).map(|i| hash_leaf(&[i])).collect();
+            let (root, proofs) = build(&leaves);
+
+            for (i, leaf) in leaves.iter().enumerate() {
+                assert!(verify_inclusion(*leaf, &proofs[i], root));
+            }
+
+            // A mismatched leaf must not verify against someone else's proof.
+            if batch_size > 1 {
+                assert!(!verify_inclusion(leaves[0], &proofs[1], root));
+            }
+        }
+    }
+}
@@ -1,8 +1,23 @@
-use maze_core::{Maze, MAZE_JOURNAL_SIZE, GRID_SIZE, GRID_DATA_SIZE, MAX_MOVES};
-use methods::{MAZE_GEN_ELF, MAZE_GEN_ID, PATH_VERIFY_ELF, PATH_VERIFY_ID};
+mod base58;
+pub mod bench;
+pub mod bundle;
+pub mod cache;
+pub mod ffi;
+pub mod journal_merkle;
+pub mod mmr;
+pub mod prover_backend;
+
+use maze_core::{identity_tree, Maze, RngBackend, IdentityMembershipProof, MAZE_JOURNAL_SIZE, NULLIFIER_JOURNAL_SIZE, PATH_MERKLE_JOURNAL_SIZE, GRID_DATA_SIZE, MAX_MOVES, MAX_JOURNEY_MAZES, MAZE_ROWS, MAZE_COLS};
+use methods::{MAZE_GEN_ELF, MAZE_GEN_ID, PATH_VERIFY_ELF, PATH_VERIFY_ID, PATH_VERIFY_MERKLE_ELF, PATH_VERIFY_MERKLE_ID, PATH_VERIFY_JOURNEY_ELF, PATH_VERIFY_JOURNEY_ID};
+use risc0_zkvm::sha::{Impl as SHA256, Sha256};
 use risc0_zkvm::{default_prover, ExecutorEnv, ProverOpts, Receipt};
 use serde::{Deserialize, Serialize};
 
+pub use bundle::{MazeProofBundle, PathProofBundle};
+pub use mmr::{verify_inclusion, InclusionProof, MmrAccumulator};
+pub use journal_merkle::{verify_inclusion as verify_batch_inclusion, InclusionProof as BatchInclusionProof};
+pub use prover_backend::{LocalProver, ProverBackend, RemoteProver};
+
 /// Receipt type for proof generation
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -54,11 +69,34 @@ impl From<ReceiptKind> for risc0_zkvm::ReceiptKind {
     }
 }
 
+impl ReceiptKind {
+    /// Single-byte tag used by [`PathProof::pack`]'s header
+    fn as_tag(&self) -> u8 {
+        match self {
+            ReceiptKind::Composite => 0,
+            ReceiptKind::Succinct => 1,
+            ReceiptKind::Groth16 => 2,
+        }
+    }
+
+    /// Inverse of [`ReceiptKind::as_tag`]
+    fn from_tag(tag: u8) -> Result<Self, Box<dyn std::error::Error>> {
+        match tag {
+            0 => Ok(ReceiptKind::Composite),
+            1 => Ok(ReceiptKind::Succinct),
+            2 => Ok(ReceiptKind::Groth16),
+            other => Err(format!("Invalid packed receipt kind tag: {}", other).into()),
+        }
+    }
+}
+
 /// Output from maze generation proof (Hash-Based Architecture)
 ///
-/// The receipt journal contains only the seed and SHA-256 hash (36 bytes),
-/// making it 97.9% smaller than the previous architecture.
-/// The actual grid data is stored separately for visualization and path verification.
+/// The receipt journal contains only the seed, SHA-256 hash, RNG backend
+/// id, braid factor, randomized start/goal cell, corridor bias, and
+/// dimensions (45 bytes), making it 97.3% smaller than the previous
+/// architecture. The actual grid data is stored separately for
+/// visualization and path verification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MazeProof {
     /// The seed used to generate this maze
@@ -67,6 +105,30 @@ pub struct MazeProof {
     /// SHA-256 hash of the grid (from the journal)
     pub grid_hash: [u8; 32],
 
+    /// The RNG backend id used to generate this maze (from the journal)
+    /// See `RngBackend::from_u8` to decode.
+    pub rng_backend: u8,
+
+    /// The braid factor (0-255) used to generate this maze (from the journal)
+    pub braid_factor: u8,
+
+    /// Cell row of the randomized entrance (from the journal)
+    pub start_row: u8,
+    /// Cell column of the randomized entrance (from the journal)
+    pub start_col: u8,
+    /// Cell row of the randomized exit (from the journal)
+    pub goal_row: u8,
+    /// Cell column of the randomized exit (from the journal)
+    pub goal_col: u8,
+
+    /// The growing-tree corridor bias (0-255) used to generate this maze (from the journal)
+    pub corridor_bias: u8,
+
+    /// Runtime cell rows this maze was generated at (from the journal, <= `MAZE_ROWS`)
+    pub rows: u8,
+    /// Runtime cell columns this maze was generated at (from the journal, <= `MAZE_COLS`)
+    pub cols: u8,
+
     /// The actual binary grid data (0=wall, 1=path)
     /// This is NOT in the journal, but is needed for:
     /// - Visualization/display
@@ -75,13 +137,134 @@ pub struct MazeProof {
     pub grid_data: Vec<Vec<u8>>,
 
     /// The receipt proving correct maze generation
-    /// Journal contains: seed (4 bytes) + grid_hash (32 bytes) = 36 bytes
+    /// Journal contains: seed (4 bytes) + grid_hash (32 bytes) + rng_backend (1 byte)
+    /// + braid_factor (1 byte) + start/goal cell coords (4 bytes) + corridor_bias (1 byte)
+    /// + rows/cols (2 bytes) = 45 bytes
     pub receipt: Receipt,
 
     /// The type of receipt generated (composite, succinct, or groth16)
     pub receipt_kind: ReceiptKind,
 }
 
+/// Magic bytes identifying a [`MazeProof::pack`] blob
+const MAZE_PACKED_MAGIC: [u8; 4] = *b"MZG1";
+
+/// Version of the [`MazeProof::pack`] binary layout
+const MAZE_PACKED_VERSION: u8 = 1;
+
+impl MazeProof {
+    /// Pack this proof into a compact binary blob: a fixed-layout header
+    /// (magic bytes, version, receipt kind tag, maze seed, grid hash, and
+    /// generation parameters as raw bytes) followed by the length-prefixed
+    /// grid data and the length-prefixed receipt. Mirrors
+    /// [`PathProof::pack`], but carries the grid data needed to run path
+    /// verification and isn't restricted to `ReceiptKind::Groth16`, since
+    /// maze proofs are normally kept around as Composite or Succinct while
+    /// path proofs are what eventually gets compressed to Groth16.
+    pub fn pack(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let receipt_bytes = serde_json::to_vec(&self.receipt)?;
+
+        let mut grid_flat = Vec::with_capacity(self.grid_data.len() * self.grid_data.first().map_or(0, Vec::len));
+        for row in &self.grid_data {
+            grid_flat.extend_from_slice(row);
+        }
+
+        let mut buf = Vec::with_capacity(
+            MAZE_PACKED_MAGIC.len() + 1 + 1 + 4 + 32 + 7 + 4 + grid_flat.len() + 4 + receipt_bytes.len(),
+        );
+        buf.extend_from_slice(&MAZE_PACKED_MAGIC);
+        buf.push(MAZE_PACKED_VERSION);
+        buf.push(self.receipt_kind.as_tag());
+        buf.extend_from_slice(&self.maze_seed.to_le_bytes());
+        buf.extend_from_slice(&self.grid_hash);
+        buf.push(self.rng_backend);
+        buf.push(self.braid_factor);
+        buf.push(self.start_row);
+        buf.push(self.start_col);
+        buf.push(self.goal_row);
+        buf.push(self.goal_col);
+        buf.push(self.corridor_bias);
+        buf.push(self.rows);
+        buf.push(self.cols);
+        buf.extend_from_slice(&(grid_flat.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&grid_flat);
+        buf.extend_from_slice(&(receipt_bytes.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&receipt_bytes);
+
+        Ok(buf)
+    }
+
+    /// Unpack a blob produced by [`MazeProof::pack`].
+    pub fn unpack(data: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 32 + 9;
+        if data.len() < HEADER_LEN + 4 {
+            return Err("Packed maze proof too short".into());
+        }
+
+        if data[0..4] != MAZE_PACKED_MAGIC {
+            return Err("Packed maze proof has invalid magic bytes".into());
+        }
+
+        let version = data[4];
+        if version != MAZE_PACKED_VERSION {
+            return Err(format!("Unsupported packed maze proof version: {}", version).into());
+        }
+
+        let receipt_kind = ReceiptKind::from_tag(data[5])?;
+        let maze_seed = u32::from_le_bytes([data[6], data[7], data[8], data[9]]);
+
+        let mut grid_hash = [0u8; 32];
+        grid_hash.copy_from_slice(&data[10..42]);
+
+        let rng_backend = data[42];
+        let braid_factor = data[43];
+        let start_row = data[44];
+        let start_col = data[45];
+        let goal_row = data[46];
+        let goal_col = data[47];
+        let corridor_bias = data[48];
+        let rows = data[49];
+        let cols = data[50];
+
+        let mut offset = HEADER_LEN;
+        let grid_len = u32::from_le_bytes(data[offset..offset + 4].try_into()?) as usize;
+        offset += 4;
+        if data.len() < offset + grid_len + 4 {
+            return Err("Packed maze proof truncated in grid section".into());
+        }
+        let grid_cols = cols as usize * 2 + 1;
+        let grid_data: Vec<Vec<u8>> = data[offset..offset + grid_len]
+            .chunks(grid_cols.max(1))
+            .map(|row| row.to_vec())
+            .collect();
+        offset += grid_len;
+
+        let receipt_len = u32::from_le_bytes(data[offset..offset + 4].try_into()?) as usize;
+        offset += 4;
+        if data.len() < offset + receipt_len {
+            return Err("Packed maze proof truncated in receipt section".into());
+        }
+        let receipt: Receipt = serde_json::from_slice(&data[offset..offset + receipt_len])?;
+
+        Ok(MazeProof {
+            maze_seed,
+            grid_hash,
+            rng_backend,
+            braid_factor,
+            start_row,
+            start_col,
+            goal_row,
+            goal_col,
+            corridor_bias,
+            rows,
+            cols,
+            grid_data,
+            receipt,
+            receipt_kind,
+        })
+    }
+}
+
 /// Output from path verification proof
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PathProof {
@@ -91,6 +274,21 @@ pub struct PathProof {
     /// The seed of the maze this path was verified against
     pub maze_seed: u32,
 
+    /// The grid hash of the maze this path was verified against (carried
+    /// over from the [`MazeProof`] it was generated from). Used as part of
+    /// the MMR leaf commitment - see [`crate::mmr`].
+    pub grid_hash: [u8; 32],
+
+    /// Root of the identity-commitment tree an anonymous credential's
+    /// membership was checked against (see [`AnonymousCredential`]), or
+    /// `None` for a proof that didn't supply one.
+    pub identity_root: Option<[u8; 32]>,
+
+    /// Per-(identity, maze) nullifier hash from an anonymous credential, or
+    /// `None` for a proof that didn't supply one. Track seen values to
+    /// reject duplicate submissions without learning who submitted them.
+    pub nullifier_hash: Option<[u8; 32]>,
+
     /// The receipt proving path validity (includes maze proof assumption)
     pub receipt: Receipt,
 
@@ -98,12 +296,280 @@ pub struct PathProof {
     pub receipt_kind: ReceiptKind,
 }
 
+/// A player's Semaphore-style anonymous maze-completion credential: a secret
+/// `identity` and its Merkle membership proof against an eligible-player
+/// tree (see [`maze_core::identity_tree`]). Passed to
+/// [`verify_path_proof_with_credential`] so the guest can prove "this path
+/// was solved by someone in the eligible-player set" without revealing which
+/// leaf `identity` corresponds to.
+#[derive(Debug, Clone)]
+pub struct AnonymousCredential {
+    /// The player's secret identity. Never leaves the host - only its
+    /// commitment and derived nullifier hash are committed to the journal.
+    pub identity: [u8; 32],
+
+    /// Root of the eligible-player tree `identity`'s commitment is a leaf
+    /// of.
+    pub identity_root: [u8; 32],
+
+    /// Sibling path from `identity_commitment = H(identity)` up to
+    /// `identity_root`.
+    pub membership_proof: IdentityMembershipProof,
+}
+
+/// Magic bytes identifying a [`PathProof::pack`] blob
+const PACKED_MAGIC: [u8; 4] = *b"MZP1";
+
+/// Version of the [`PathProof::pack`] binary layout
+///
+/// Bumped to 2 when `grid_hash` was added to the header.
+const PACKED_VERSION: u8 = 2;
+
+impl PathProof {
+    /// Pack this proof into a fixed-layout binary blob for on-chain or
+    /// network transport - a short header (magic bytes, version, receipt
+    /// kind tag, maze seed as LE u32, grid hash, validity flag) followed by
+    /// the length-prefixed Groth16 seal and the length-prefixed receipt
+    /// itself (as `serde_json` bytes), so a Groth16 proof round-trips
+    /// losslessly through [`PathProof::unpack`]. Since the whole receipt is
+    /// embedded, the blob is larger than that receipt's own compact JSON.
+    ///
+    /// Only `ReceiptKind::Groth16` proofs have a seal compact enough for
+    /// this to be worthwhile - other receipt kinds return an error.
+    pub fn pack(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let seal = groth16_seal(&self.receipt)?;
+        let receipt_bytes = serde_json::to_vec(&self.receipt)?;
+
+        let mut buf = Vec::with_capacity(
+            PACKED_MAGIC.len() + 1 + 1 + 4 + 32 + 1 + 4 + seal.len() + 4 + receipt_bytes.len(),
+        );
+        buf.extend_from_slice(&PACKED_MAGIC);
+        buf.push(PACKED_VERSION);
+        buf.push(self.receipt_kind.as_tag());
+        buf.extend_from_slice(&self.maze_seed.to_le_bytes());
+        buf.extend_from_slice(&self.grid_hash);
+        buf.push(self.is_valid as u8);
+        buf.extend_from_slice(&(seal.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&seal);
+        buf.extend_from_slice(&(receipt_bytes.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&receipt_bytes);
+
+        Ok(buf)
+    }
+
+    /// Unpack a blob produced by [`PathProof::pack`].
+    pub fn unpack(data: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        const HEADER_LEN: usize = 4 + 1 + 1 + 4 + 32 + 1;
+        if data.len() < HEADER_LEN + 4 {
+            return Err("Packed proof too short".into());
+        }
+
+        if data[0..4] != PACKED_MAGIC {
+            return Err("Packed proof has invalid magic bytes".into());
+        }
+
+        let version = data[4];
+        if version != PACKED_VERSION {
+            return Err(format!("Unsupported packed proof version: {}", version).into());
+        }
+
+        let receipt_kind = ReceiptKind::from_tag(data[5])?;
+        let maze_seed = u32::from_le_bytes([data[6], data[7], data[8], data[9]]);
+
+        let mut grid_hash = [0u8; 32];
+        grid_hash.copy_from_slice(&data[10..42]);
+
+        let is_valid = data[42] != 0;
+
+        let mut offset = HEADER_LEN;
+        let seal_len = u32::from_le_bytes(data[offset..offset + 4].try_into()?) as usize;
+        offset += 4;
+        if data.len() < offset + seal_len + 4 {
+            return Err("Packed proof truncated in seal section".into());
+        }
+        offset += seal_len; // the seal is re-derivable from the receipt; skip over it
+
+        let receipt_len = u32::from_le_bytes(data[offset..offset + 4].try_into()?) as usize;
+        offset += 4;
+        if data.len() < offset + receipt_len {
+            return Err("Packed proof truncated in receipt section".into());
+        }
+        let receipt: Receipt = serde_json::from_slice(&data[offset..offset + receipt_len])?;
+
+        // `identity_root`/`nullifier_hash` aren't in the packed header -
+        // they're re-derived from the receipt's own journal, which already
+        // carries them (see `verify_path_proof_with_credential`'s decode).
+        // An all-zero extension means the proof carried no credential.
+        let (identity_root, nullifier_hash) = {
+            let journal_bytes = &receipt.journal.bytes;
+            if journal_bytes.len() >= 8 + NULLIFIER_JOURNAL_SIZE {
+                let mut root = [0u8; 32];
+                root.copy_from_slice(&journal_bytes[8..8 + 32]);
+                let mut hash = [0u8; 32];
+                hash.copy_from_slice(&journal_bytes[8 + 32..8 + 64]);
+                if root == [0u8; 32] && hash == [0u8; 32] {
+                    (None, None)
+                } else {
+                    (Some(root), Some(hash))
+                }
+            } else {
+                (None, None)
+            }
+        };
+
+        Ok(PathProof {
+            is_valid,
+            maze_seed,
+            grid_hash,
+            identity_root,
+            nullifier_hash,
+            receipt,
+            receipt_kind,
+        })
+    }
+
+    /// Pack this proof and encode it as a self-verifying Base58Check string,
+    /// short enough to paste into a chat message or URL. Only Groth16
+    /// receipts can be packed - see [`PathProof::pack`].
+    pub fn share(&self) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(encode_base58check(&self.pack()?))
+    }
+
+    /// Inverse of [`PathProof::share`].
+    pub fn import(s: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::unpack(&decode_base58check(s)?)
+    }
+
+    /// Serialize this proof into the minimal payload an on-chain verifier
+    /// (e.g. a Soroban contract) needs: `[version][image_id (32)][journal_len
+    /// (4)][journal][seal]`. Errors unless `receipt_kind == Groth16` - the
+    /// only kind with a seal small enough to be worth passing on-chain, same
+    /// restriction as [`PathProof::pack`].
+    ///
+    /// Unlike `pack`/`unpack`, this doesn't carry the full `Receipt` serde
+    /// blob - a contract only ever needs the seal, the journal it commits
+    /// to, and the image ID to check the seal against, not the receipt's
+    /// other bookkeeping, which is multi-MB for composite receipts and
+    /// awkward to hand to a smart contract regardless of kind.
+    pub fn to_onchain_calldata(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        if self.receipt_kind != ReceiptKind::Groth16 {
+            return Err(format!(
+                "Only Groth16 proofs can be exported as on-chain calldata, got {}",
+                self.receipt_kind
+            )
+            .into());
+        }
+
+        let seal = groth16_seal(&self.receipt)?;
+        let journal = &self.receipt.journal.bytes;
+        let image_id = expected_path_verify_image_id();
+
+        let mut buf = Vec::with_capacity(1 + 32 + 4 + journal.len() + seal.len());
+        buf.push(ONCHAIN_CALLDATA_VERSION);
+        buf.extend_from_slice(image_id.as_bytes());
+        buf.extend_from_slice(&(journal.len() as u32).to_le_bytes());
+        buf.extend_from_slice(journal);
+        buf.extend_from_slice(&seal);
+
+        Ok(buf)
+    }
+}
+
+/// Version of the [`PathProof::to_onchain_calldata`] binary layout.
+const ONCHAIN_CALLDATA_VERSION: u8 = 1;
+
+/// Decode calldata produced by [`PathProof::to_onchain_calldata`] and return
+/// `(is_valid, maze_seed)` from its journal.
+///
+/// Mirrors the structural checks (version tag, image ID pin, length
+/// framing) a contract would run before handing the seal to its Groth16
+/// precompile - the pairing check itself only makes sense run by the
+/// precompile inside the contract, so this stops short of it, the same way
+/// [`verify_path_proof_receipt`]'s cheap checks run before its call to
+/// `Receipt::verify`.
+pub fn verify_onchain_calldata(data: &[u8]) -> Result<(bool, u32), Box<dyn std::error::Error>> {
+    const HEADER_LEN: usize = 1 + 32 + 4;
+    if data.len() < HEADER_LEN {
+        return Err("On-chain calldata too short".into());
+    }
+
+    let version = data[0];
+    if version != ONCHAIN_CALLDATA_VERSION {
+        return Err(format!("Unsupported on-chain calldata version: {}", version).into());
+    }
+
+    let expected_image_id = expected_path_verify_image_id();
+    if data[1..33] != *expected_image_id.as_bytes() {
+        return Err("Calldata image ID does not match the expected path-verify guest".into());
+    }
+
+    let journal_len = u32::from_le_bytes(data[33..37].try_into()?) as usize;
+    if journal_len < 8 {
+        return Err("Calldata journal is too short to contain is_valid/maze_seed".into());
+    }
+    if data.len() < HEADER_LEN + journal_len {
+        return Err("On-chain calldata truncated in journal section".into());
+    }
+    let journal = &data[HEADER_LEN..HEADER_LEN + journal_len];
+
+    let is_valid = u32::from_le_bytes([journal[0], journal[1], journal[2], journal[3]]) != 0;
+    let maze_seed = u32::from_le_bytes([journal[4], journal[5], journal[6], journal[7]]);
+
+    Ok((is_valid, maze_seed))
+}
+
+/// Double SHA-256, as used by Base58Check's checksum.
+fn double_sha256(data: &[u8]) -> [u8; 32] {
+    let first = SHA256::hash_bytes(data);
+    let second = SHA256::hash_bytes(first.as_bytes());
+    let mut out = [0u8; 32];
+    out.copy_from_slice(second.as_bytes());
+    out
+}
+
+/// Encode `payload` as Base58Check: the payload followed by the first 4
+/// bytes of its double-SHA256 checksum, all Base58-encoded.
+fn encode_base58check(payload: &[u8]) -> String {
+    let checksum = double_sha256(payload);
+    let mut buf = Vec::with_capacity(payload.len() + 4);
+    buf.extend_from_slice(payload);
+    buf.extend_from_slice(&checksum[..4]);
+    base58::encode(&buf)
+}
+
+/// Decode a Base58Check string, verifying its trailing 4-byte checksum and
+/// returning the payload with the checksum stripped off.
+fn decode_base58check(s: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let decoded = base58::decode(s)?;
+    if decoded.len() < 4 {
+        return Err("Base58Check string is too short to contain a checksum".into());
+    }
+
+    let (payload, checksum) = decoded.split_at(decoded.len() - 4);
+    let expected = double_sha256(payload);
+    if checksum != &expected[..4] {
+        return Err("Base58Check checksum mismatch".into());
+    }
+
+    Ok(payload.to_vec())
+}
+
+/// Extract the raw Groth16 seal bytes from a receipt, for [`PathProof::pack`].
+fn groth16_seal(receipt: &Receipt) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let groth16_receipt = receipt
+        .inner
+        .groth16()
+        .map_err(|e| format!("Proof is not a Groth16 receipt, cannot pack: {}", e))?;
+
+    Ok(groth16_receipt.seal.clone())
+}
+
 /// Generate a maze proof from a seed (Hash-Based Architecture).
 ///
 /// This creates a cryptographic proof that a maze was correctly generated
 /// from the given seed. The proof commits to a SHA-256 hash of the grid
-/// (36 bytes) instead of the full grid (1,685 bytes), reducing proof size
-/// by 97.9%.
+/// (45 bytes) instead of the full grid (1,685 bytes), reducing proof size
+/// by 97.3%.
 ///
 /// The actual grid data is also returned for visualization and path verification,
 /// but it's NOT embedded in the proof journal.
@@ -130,13 +596,131 @@ pub fn generate_maze_proof(
     maze_seed: u32,
     receipt_kind: ReceiptKind,
 ) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    generate_maze_proof_with_backend(maze_seed, receipt_kind, RngBackend::Minstd)
+}
+
+/// Generate a maze proof from a seed using a specific RNG backend.
+///
+/// Identical to [`generate_maze_proof`] except the caller selects which
+/// `MazeRng` backend (`Minstd`, `Pcg32`, or `Chacha8`) carves the maze. The
+/// backend id is committed into the journal so `verify_path_proof` can
+/// regenerate the exact same grid.
+pub fn generate_maze_proof_with_backend(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    generate_maze_proof_with_options(maze_seed, receipt_kind, rng_backend, 0)
+}
+
+/// Generate a maze proof from a seed using a specific RNG backend and braid factor.
+///
+/// `braid_factor` (0-255) is the probability, out of 256, that a dead end
+/// in the carved maze gets an extra passage opened to a random neighbor,
+/// introducing cycles so multiple valid solutions exist. It is committed
+/// into the journal alongside the RNG backend so the maze hash binds the
+/// exact generation configuration. Uses the default `corridor_bias` (255,
+/// depth-first on 255 of every 256 draws) - see [`generate_maze_proof_with_corridor_bias`]
+/// for control over the growing-tree carving character.
+pub fn generate_maze_proof_with_options(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    generate_maze_proof_with_corridor_bias(maze_seed, receipt_kind, rng_backend, braid_factor, 255)
+}
+
+/// Generate a maze proof from a seed using a specific RNG backend, braid
+/// factor, and growing-tree `corridor_bias`.
+///
+/// `corridor_bias` (0-255) weights the growing-tree frontier selection: 255
+/// picks the newest frontier cell on 255 of every 256 draws
+/// (recursive-backtracker-like corridors), 0 is
+/// uniformly random (Prim's-like bushy branching). It is committed into the
+/// journal alongside the other generation parameters so the maze hash binds
+/// the exact carving configuration.
+pub fn generate_maze_proof_with_corridor_bias(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+    corridor_bias: u8,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    generate_maze_proof_with_dimensions(
+        maze_seed, receipt_kind, rng_backend, braid_factor, corridor_bias, MAZE_ROWS as u8, MAZE_COLS as u8,
+    )
+}
+
+/// Generate a maze proof from a seed using a specific RNG backend, braid
+/// factor, growing-tree `corridor_bias`, and runtime dimensions.
+///
+/// `rows`/`cols` (each <= `MAZE_ROWS`/`MAZE_COLS`) are the maze's actual
+/// cell dimensions, committed into the journal so a proof no longer
+/// implicitly assumes a fixed 20x20 grid. The guest is still compiled with
+/// a fixed maximum - use [`maze_core::DynMaze`] under the `alloc` feature
+/// for non-provable, larger-than-guest-max generation.
+pub fn generate_maze_proof_with_dimensions(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+    corridor_bias: u8,
+    rows: u8,
+    cols: u8,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    generate_maze_proof_with_prover(
+        maze_seed, receipt_kind, rng_backend, braid_factor, corridor_bias, rows, cols, &LocalProver,
+    )
+}
+
+/// Identical to [`generate_maze_proof_with_dimensions`], but proves through
+/// `backend` instead of always running on the local machine - e.g. a
+/// [`RemoteProver`] so Groth16 compression runs on a machine sized for it
+/// instead of the caller's own. The assumption-composition logic downstream
+/// (`env::verify`, image-ID writing, journal decode) is unaffected by which
+/// backend produced the receipt.
+pub fn generate_maze_proof_with_prover(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+    corridor_bias: u8,
+    rows: u8,
+    cols: u8,
+    backend: &dyn ProverBackend,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    let cache_key = generate_cache_key(maze_seed, receipt_kind, rng_backend, braid_factor, corridor_bias, rows, cols);
+    if let Some(cached) = cache::get(&cache_key) {
+        if let Ok(maze_proof) = serde_json::from_slice::<MazeProof>(&cached) {
+            tracing::info!("Loaded maze proof for seed {} from cache", maze_seed);
+            return Ok(maze_proof);
+        }
+    }
+
     tracing::info!("Generating maze proof for seed {} with receipt kind: {}", maze_seed, receipt_kind);
 
     // Build execution environment
     let mut builder = ExecutorEnv::builder();
     builder.write(&maze_seed)?;
+    builder.write(&(rng_backend as u8))?;
+    builder.write(&braid_factor)?;
+    builder.write(&corridor_bias)?;
+    builder.write(&rows)?;
+    builder.write(&cols)?;
     let env = builder.build()?;
 
+    // Flattened to raw bytes alongside the builder calls above, for a
+    // backend (see `prover_backend`) that can't accept an in-process
+    // `ExecutorEnv` at all.
+    let mut witness_bytes = Vec::new();
+    witness_bytes.extend(prover_backend::word_bytes(&maze_seed)?);
+    witness_bytes.extend(prover_backend::word_bytes(&(rng_backend as u8))?);
+    witness_bytes.extend(prover_backend::word_bytes(&braid_factor)?);
+    witness_bytes.extend(prover_backend::word_bytes(&corridor_bias)?);
+    witness_bytes.extend(prover_backend::word_bytes(&rows)?);
+    witness_bytes.extend(prover_backend::word_bytes(&cols)?);
+
     // Configure prover options with desired receipt kind
     let opts = match receipt_kind {
         ReceiptKind::Composite => ProverOpts::composite(),
@@ -145,15 +729,14 @@ pub fn generate_maze_proof(
     };
 
     // Generate proof
-    let prover = default_prover();
-    let prove_info = prover
-        .prove_with_opts(env, MAZE_GEN_ELF, &opts)
+    let receipt = backend
+        .prove_with_opts(env, &witness_bytes, &[], MAZE_GEN_ELF, &opts, receipt_kind)
         .map_err(|e| format!("Failed to generate maze proof: {}", e))?;
 
-    let receipt = prove_info.receipt;
-
     // Decode journal
-    // Format: maze_seed (u32, 4 bytes) + grid_hash (32 bytes) = 36 bytes
+    // Format: maze_seed (u32, 4 bytes) + grid_hash (32 bytes) + rng_backend (1 byte)
+    // + braid_factor (1 byte) + start/goal cell coords (4 bytes) + corridor_bias (1 byte)
+    // + rows/cols (2 bytes) = 45 bytes
     let journal_bytes = &receipt.journal.bytes;
     if journal_bytes.len() < MAZE_JOURNAL_SIZE {
         return Err(format!(
@@ -172,23 +755,93 @@ pub fn generate_maze_proof(
     ]);
 
     let mut grid_hash = [0u8; 32];
-    grid_hash.copy_from_slice(&journal_bytes[4..MAZE_JOURNAL_SIZE]);
+    grid_hash.copy_from_slice(&journal_bytes[4..4 + 32]);
+
+    let rng_backend_id = journal_bytes[4 + 32];
+    let braid_factor_out = journal_bytes[4 + 32 + 1];
+    let start_row = journal_bytes[4 + 32 + 2];
+    let start_col = journal_bytes[4 + 32 + 3];
+    let goal_row = journal_bytes[4 + 32 + 4];
+    let goal_col = journal_bytes[4 + 32 + 5];
+    let corridor_bias_out = journal_bytes[4 + 32 + 6];
+    let rows_out = journal_bytes[4 + 32 + 7];
+    let cols_out = journal_bytes[4 + 32 + 8];
 
     // IMPORTANT: We need to regenerate the maze to get the grid data
     // for visualization and path verification input.
     // This is safe because maze generation is deterministic.
     tracing::info!("Regenerating maze to extract grid data...");
-    let grid_data = regenerate_maze_grid(maze_seed)?;
+    let grid_data = regenerate_maze_grid(
+        maze_seed,
+        RngBackend::from_u8(rng_backend_id),
+        braid_factor_out,
+        corridor_bias_out,
+        rows_out,
+        cols_out,
+    )?;
 
     tracing::info!("Maze proof generated successfully (journal: {} bytes, receipt kind: {})", MAZE_JOURNAL_SIZE, receipt_kind);
 
-    Ok(MazeProof {
+    let maze_proof = MazeProof {
         maze_seed: maze_seed_out,
         grid_hash,
+        rng_backend: rng_backend_id,
+        braid_factor: braid_factor_out,
+        start_row,
+        start_col,
+        goal_row,
+        goal_col,
+        corridor_bias: corridor_bias_out,
+        rows: rows_out,
+        cols: cols_out,
         grid_data,
         receipt,
         receipt_kind,
-    })
+    };
+
+    if let Ok(serialized) = serde_json::to_vec(&maze_proof) {
+        cache::put(&cache_key, &serialized);
+    }
+
+    Ok(maze_proof)
+}
+
+/// Cache key for a `generate_maze_proof*` call: every guest input that
+/// determines the resulting proof, so an identical call can load from
+/// [`cache`] instead of re-proving.
+fn generate_cache_key(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+    corridor_bias: u8,
+    rows: u8,
+    cols: u8,
+) -> Vec<u8> {
+    let mut key = Vec::with_capacity(4 + 1 + 1 + 1 + 1 + 1 + 1);
+    key.extend_from_slice(&maze_seed.to_le_bytes());
+    key.push(receipt_kind.as_tag());
+    key.push(rng_backend as u8);
+    key.push(braid_factor);
+    key.push(corridor_bias);
+    key.push(rows);
+    key.push(cols);
+    key
+}
+
+/// Async variant of [`generate_maze_proof`]: offloads the synchronous,
+/// CPU-heavy proving call to a blocking thread so awaiting it doesn't stall
+/// the async executor it's called from - e.g. an `api-server` handler. Runs
+/// on [`LocalProver`]; use [`generate_maze_proof_with_prover`] directly (via
+/// your own `spawn_blocking`) to offload to a [`RemoteProver`] instead.
+pub async fn generate_maze_proof_async(
+    maze_seed: u32,
+    receipt_kind: ReceiptKind,
+) -> Result<MazeProof, Box<dyn std::error::Error>> {
+    tokio::task::spawn_blocking(move || generate_maze_proof(maze_seed, receipt_kind).map_err(|e| e.to_string()))
+        .await
+        .map_err(|e| format!("maze proof generation task panicked: {}", e))?
+        .map_err(Into::into)
 }
 
 /// Regenerate a maze grid from a seed (for host-side use only).
@@ -197,8 +850,15 @@ pub fn generate_maze_proof(
 /// so we can extract the grid data for display and path verification.
 ///
 /// This is safe because maze generation is deterministic (same algorithm and RNG).
-fn regenerate_maze_grid(seed: u32) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
-    let maze = Maze::generate(20, 20, seed);
+fn regenerate_maze_grid(
+    seed: u32,
+    rng_backend: RngBackend,
+    braid_factor: u8,
+    corridor_bias: u8,
+    rows: u8,
+    cols: u8,
+) -> Result<Vec<Vec<u8>>, Box<dyn std::error::Error>> {
+    let maze = Maze::generate_with_corridor_bias(rows as usize, cols as usize, seed, rng_backend, braid_factor, corridor_bias);
     let grid = maze.to_binary_grid_vec();
 
     Ok(grid)
@@ -242,10 +902,46 @@ pub fn verify_path_proof(
     maze_proof: &MazeProof,
     moves: Vec<u8>,
     receipt_kind_override: Option<ReceiptKind>,
+) -> Result<PathProof, Box<dyn std::error::Error>> {
+    verify_path_proof_with_credential(maze_proof, moves, receipt_kind_override, None)
+}
+
+/// Identical to [`verify_path_proof`], but optionally attaches an
+/// [`AnonymousCredential`] so the resulting [`PathProof`] also carries an
+/// `identity_root`/`nullifier_hash` pair a verifier can check against an
+/// accepted root set and a seen-nullifiers set, without learning who solved
+/// the maze. Passing `None` is exactly [`verify_path_proof`].
+pub fn verify_path_proof_with_credential(
+    maze_proof: &MazeProof,
+    moves: Vec<u8>,
+    receipt_kind_override: Option<ReceiptKind>,
+    credential: Option<AnonymousCredential>,
+) -> Result<PathProof, Box<dyn std::error::Error>> {
+    verify_path_proof_with_prover(maze_proof, moves, receipt_kind_override, credential, &LocalProver)
+}
+
+/// Identical to [`verify_path_proof_with_credential`], but proves through
+/// `backend` instead of always running on the local machine - see
+/// [`generate_maze_proof_with_prover`] for the same extension point on the
+/// maze-generation side.
+pub fn verify_path_proof_with_prover(
+    maze_proof: &MazeProof,
+    moves: Vec<u8>,
+    receipt_kind_override: Option<ReceiptKind>,
+    credential: Option<AnonymousCredential>,
+    backend: &dyn ProverBackend,
 ) -> Result<PathProof, Box<dyn std::error::Error>> {
     // Use override if provided, otherwise auto-detect from maze proof
     let receipt_kind = receipt_kind_override.unwrap_or(maze_proof.receipt_kind);
 
+    let cache_key = verify_cache_key(maze_proof, &moves, receipt_kind, credential.as_ref())?;
+    if let Some(cached) = cache::get(&cache_key) {
+        if let Ok(path_proof) = serde_json::from_slice::<PathProof>(&cached) {
+            tracing::info!("Loaded path proof for maze seed {} from cache", maze_proof.maze_seed);
+            return Ok(path_proof);
+        }
+    }
+
     tracing::info!("Verifying path proof for maze seed {} with receipt kind: {}", maze_proof.maze_seed, receipt_kind);
 
     // Prepare inputs for path verification guest
@@ -254,11 +950,14 @@ pub fn verify_path_proof(
     // Extract the maze journal from the receipt (seed + hash)
     let maze_journal_bytes = &maze_proof.receipt.journal.bytes;
 
-    // Flatten grid_data for guest input
+    // Flatten grid_data for guest input. Stride is the maze's own
+    // grid_cols (not the fixed GRID_SIZE constant) so the layout matches
+    // what the guest reconstructs from the committed rows/cols.
+    let grid_cols = maze_proof.cols as usize * 2 + 1;
     let mut grid_flat = [0u8; GRID_DATA_SIZE];
     for (i, row) in maze_proof.grid_data.iter().enumerate() {
         for (j, &cell) in row.iter().enumerate() {
-            grid_flat[i * GRID_SIZE + j] = cell;
+            grid_flat[i * grid_cols + j] = cell;
         }
     }
 
@@ -278,7 +977,7 @@ pub fn verify_path_proof(
     }
     builder.write_slice(&image_id_bytes);
 
-    // Write maze journal (seed + hash, 36 bytes)
+    // Write maze journal (seed + hash + rng_backend + braid_factor + start/goal + corridor_bias + rows/cols, 45 bytes)
     builder.write_slice(maze_journal_bytes);
 
     // Write grid data as untrusted input (will be verified via hash in guest)
@@ -288,8 +987,51 @@ pub fn verify_path_proof(
     builder.write(&move_count)?;
     builder.write_slice(&moves[..move_count as usize]);
 
+    // Optional anonymous maze-completion credential (see
+    // `maze_core::identity_tree` and [`AnonymousCredential`])
+    match &credential {
+        Some(cred) => {
+            builder.write(&1u8)?;
+            builder.write_slice(&cred.identity);
+            builder.write_slice(&cred.identity_root);
+            let (siblings, path_bits) = &cred.membership_proof;
+            for sibling in siblings {
+                builder.write_slice(sibling);
+            }
+            builder.write(path_bits)?;
+        }
+        None => {
+            builder.write(&0u8)?;
+        }
+    }
+
     let env = builder.build()?;
 
+    // Flattened to raw bytes alongside the builder calls above, for a
+    // backend (see `prover_backend`) that can't accept an in-process
+    // `ExecutorEnv` at all.
+    let mut witness_bytes = Vec::new();
+    witness_bytes.extend_from_slice(&image_id_bytes);
+    witness_bytes.extend_from_slice(maze_journal_bytes);
+    witness_bytes.extend_from_slice(&grid_flat);
+    witness_bytes.extend(prover_backend::word_bytes(&move_count)?);
+    witness_bytes.extend_from_slice(&moves[..move_count as usize]);
+    match &credential {
+        Some(cred) => {
+            witness_bytes.extend(prover_backend::word_bytes(&1u8)?);
+            witness_bytes.extend_from_slice(&cred.identity);
+            witness_bytes.extend_from_slice(&cred.identity_root);
+            let (siblings, path_bits) = &cred.membership_proof;
+            for sibling in siblings {
+                witness_bytes.extend_from_slice(sibling);
+            }
+            witness_bytes.extend(prover_backend::word_bytes(path_bits)?);
+        }
+        None => {
+            witness_bytes.extend(prover_backend::word_bytes(&0u8)?);
+        }
+    }
+
     // Configure prover options with detected receipt kind
     let opts = match receipt_kind {
         ReceiptKind::Composite => ProverOpts::composite(),
@@ -301,19 +1043,28 @@ pub fn verify_path_proof(
     // Note: This creates a "conditional receipt" with an assumption
     // The assumption will be resolved when we request a succinct or groth16 receipt
     tracing::info!("Generating path verification proof...");
-    let prover = default_prover();
-    let prove_info = prover
-        .prove_with_opts(env, PATH_VERIFY_ELF, &opts)
+    let receipt = backend
+        .prove_with_opts(
+            env,
+            &witness_bytes,
+            &[maze_proof.receipt.clone()],
+            PATH_VERIFY_ELF,
+            &opts,
+            receipt_kind,
+        )
         .map_err(|e| format!("Failed to generate path proof: {}", e))?;
 
-    let receipt = prove_info.receipt;
-
     // Decode journal
-    // Format: is_valid (u32, 0 or 1) + maze_seed (u32)
+    // Format: is_valid (u32) + maze_seed (u32) + identity_root (32 bytes,
+    // zeroed if no credential) + nullifier_hash (32 bytes, zeroed if no
+    // credential) - the nullifier extension is always committed so the
+    // journal stays a fixed size whether or not a credential was supplied.
     let journal_bytes = &receipt.journal.bytes;
-    if journal_bytes.len() < 8 {
+    let expected_journal_len = 8 + NULLIFIER_JOURNAL_SIZE;
+    if journal_bytes.len() < expected_journal_len {
         return Err(format!(
-            "Journal too short: expected 8 bytes, got {}",
+            "Journal too short: expected {} bytes, got {}",
+            expected_journal_len,
             journal_bytes.len()
         )
         .into());
@@ -333,21 +1084,526 @@ pub fn verify_path_proof(
         journal_bytes[7],
     ]);
 
+    // The values are only meaningful when a credential was actually
+    // supplied - `credential.is_some()` is the ground truth for that,
+    // rather than guessing from whether the guest committed all-zero bytes.
+    let (identity_root_out, nullifier_hash_out) = if credential.is_some() {
+        let mut identity_root = [0u8; 32];
+        identity_root.copy_from_slice(&journal_bytes[8..8 + 32]);
+        let mut nullifier_hash = [0u8; 32];
+        nullifier_hash.copy_from_slice(&journal_bytes[8 + 32..8 + 64]);
+        (Some(identity_root), Some(nullifier_hash))
+    } else {
+        (None, None)
+    };
+
     tracing::info!("Path proof generated successfully");
 
-    Ok(PathProof {
+    let path_proof = PathProof {
+        is_valid: is_valid_u32 != 0,
+        maze_seed: maze_seed_out,
+        grid_hash: maze_proof.grid_hash,
+        identity_root: identity_root_out,
+        nullifier_hash: nullifier_hash_out,
+        receipt,
+        receipt_kind,
+    };
+
+    if let Ok(serialized) = serde_json::to_vec(&path_proof) {
+        cache::put(&cache_key, &serialized);
+    }
+
+    Ok(path_proof)
+}
+
+/// Async variant of [`verify_path_proof`]: offloads the synchronous,
+/// CPU-heavy proving call to a blocking thread so awaiting it doesn't stall
+/// the async executor it's called from. Runs on [`LocalProver`]; use
+/// [`verify_path_proof_with_prover`] directly (via your own
+/// `spawn_blocking`) to offload to a [`RemoteProver`] instead.
+pub async fn verify_path_proof_async(
+    maze_proof: MazeProof,
+    moves: Vec<u8>,
+    receipt_kind_override: Option<ReceiptKind>,
+) -> Result<PathProof, Box<dyn std::error::Error>> {
+    tokio::task::spawn_blocking(move || {
+        verify_path_proof(&maze_proof, moves, receipt_kind_override).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("path proof verification task panicked: {}", e))?
+    .map_err(Into::into)
+}
+
+/// Output from a multi-maze "journey" proof (see the `path-verify-journey`
+/// guest): one receipt attesting an ordered sequence of mazes was solved
+/// back-to-back, with every maze's generation proof folded in as its own
+/// assumption rather than producing a separate [`PathProof`] per maze.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JourneyProof {
+    /// The seed of each maze in the journey, in sequence order.
+    pub maze_seeds: Vec<u32>,
+
+    /// Whether every maze segment in the journey was solved - `false` if any
+    /// single segment failed its grid hash, bounds, or move validation.
+    pub all_valid: bool,
+
+    /// The receipt proving the whole journey (includes every maze's
+    /// generation assumption)
+    pub receipt: Receipt,
+
+    /// The type of receipt generated (composite, succinct, or groth16)
+    pub receipt_kind: ReceiptKind,
+}
+
+/// Prove an ordered sequence of mazes was solved back-to-back, composing
+/// every maze's generation receipt in as an assumption of one final receipt
+/// instead of producing `maze_proofs.len()` independent [`PathProof`]s - see
+/// the `path-verify-journey` guest's module docs for the composition and
+/// per-segment validation this proves.
+///
+/// `maze_proofs` and `moves_per_maze` must be the same length, in the order
+/// the player walked them, and capped at [`maze_core::MAX_JOURNEY_MAZES`].
+/// `receipt_kind_override` defaults to the first maze's receipt kind, the
+/// same auto-detection [`verify_path_proof`] uses.
+pub fn verify_journey_proof(
+    maze_proofs: &[MazeProof],
+    moves_per_maze: Vec<Vec<u8>>,
+    receipt_kind_override: Option<ReceiptKind>,
+) -> Result<JourneyProof, Box<dyn std::error::Error>> {
+    verify_journey_proof_with_prover(maze_proofs, moves_per_maze, receipt_kind_override, &LocalProver)
+}
+
+/// Identical to [`verify_journey_proof`], but proves through `backend`
+/// instead of always running on the local machine - see
+/// [`verify_path_proof_with_prover`] for the same extension point on the
+/// single-maze path.
+pub fn verify_journey_proof_with_prover(
+    maze_proofs: &[MazeProof],
+    moves_per_maze: Vec<Vec<u8>>,
+    receipt_kind_override: Option<ReceiptKind>,
+    backend: &dyn ProverBackend,
+) -> Result<JourneyProof, Box<dyn std::error::Error>> {
+    if maze_proofs.len() != moves_per_maze.len() {
+        return Err(format!(
+            "maze_proofs and moves_per_maze must be the same length, got {} and {}",
+            maze_proofs.len(),
+            moves_per_maze.len()
+        )
+        .into());
+    }
+    if maze_proofs.len() > MAX_JOURNEY_MAZES {
+        return Err(format!(
+            "Journey has {} mazes, exceeding the guest's maximum of {}",
+            maze_proofs.len(),
+            MAX_JOURNEY_MAZES
+        )
+        .into());
+    }
+
+    let receipt_kind = receipt_kind_override
+        .or_else(|| maze_proofs.first().map(|p| p.receipt_kind))
+        .unwrap_or_default();
+
+    tracing::info!(
+        "Verifying journey proof for {} mazes with receipt kind: {}",
+        maze_proofs.len(),
+        receipt_kind
+    );
+
+    let mut builder = ExecutorEnv::builder();
+    let mut witness_bytes = Vec::new();
+    let mut assumptions = Vec::with_capacity(maze_proofs.len());
+
+    let count = maze_proofs.len() as u32;
+    builder.write(&count)?;
+    witness_bytes.extend(prover_backend::word_bytes(&count)?);
+
+    // Convert MAZE_GEN_ID ([u32; 8]) to [u8; 32], same conversion
+    // `verify_path_proof_with_prover` does for its own single assumption.
+    let mut image_id_bytes = [0u8; 32];
+    for (i, &word) in MAZE_GEN_ID.iter().enumerate() {
+        image_id_bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+    }
+
+    for (maze_proof, moves) in maze_proofs.iter().zip(moves_per_maze.iter()) {
+        builder.add_assumption(maze_proof.receipt.clone());
+        assumptions.push(maze_proof.receipt.clone());
+
+        builder.write_slice(&image_id_bytes);
+        witness_bytes.extend_from_slice(&image_id_bytes);
+
+        let maze_journal_bytes = &maze_proof.receipt.journal.bytes;
+        builder.write_slice(maze_journal_bytes);
+        witness_bytes.extend_from_slice(maze_journal_bytes);
+
+        // Flatten this segment's grid_data, stride is its own grid_cols -
+        // same layout `verify_path_proof_with_prover` writes for its grid.
+        let grid_cols = maze_proof.cols as usize * 2 + 1;
+        let mut grid_flat = [0u8; GRID_DATA_SIZE];
+        for (i, row) in maze_proof.grid_data.iter().enumerate() {
+            for (j, &cell) in row.iter().enumerate() {
+                grid_flat[i * grid_cols + j] = cell;
+            }
+        }
+        let grid_len = maze_proof.grid_data.len() * grid_cols;
+        builder.write_slice(&grid_flat[..grid_len]);
+        witness_bytes.extend_from_slice(&grid_flat[..grid_len]);
+
+        let move_count = moves.len().min(u16::MAX as usize) as u16;
+        builder.write(&move_count)?;
+        witness_bytes.extend(prover_backend::word_bytes(&move_count)?);
+        builder.write_slice(&moves[..move_count as usize]);
+        witness_bytes.extend_from_slice(&moves[..move_count as usize]);
+    }
+
+    let env = builder.build()?;
+
+    let opts = match receipt_kind {
+        ReceiptKind::Composite => ProverOpts::composite(),
+        ReceiptKind::Succinct => ProverOpts::succinct(),
+        ReceiptKind::Groth16 => ProverOpts::groth16(),
+    };
+
+    tracing::info!("Generating journey verification proof...");
+    let receipt = backend
+        .prove_with_opts(env, &witness_bytes, &assumptions, PATH_VERIFY_JOURNEY_ELF, &opts, receipt_kind)
+        .map_err(|e| format!("Failed to generate journey proof: {}", e))?;
+
+    // Decode the variable-length journal: count (u32) + count seeds (u32
+    // each) + all_valid (u32) - see the guest's `commit_journey`.
+    let journal_bytes = &receipt.journal.bytes;
+    if journal_bytes.len() < 4 {
+        return Err("Journey journal too short to contain a maze count".into());
+    }
+    let committed_count = u32::from_le_bytes([
+        journal_bytes[0],
+        journal_bytes[1],
+        journal_bytes[2],
+        journal_bytes[3],
+    ]) as usize;
+
+    let seeds_end = 4 + committed_count * 4;
+    if journal_bytes.len() < seeds_end + 4 {
+        return Err(format!(
+            "Journey journal too short: expected at least {} bytes for {} mazes, got {}",
+            seeds_end + 4,
+            committed_count,
+            journal_bytes.len()
+        )
+        .into());
+    }
+
+    let mut maze_seeds = Vec::with_capacity(committed_count);
+    for i in 0..committed_count {
+        let offset = 4 + i * 4;
+        maze_seeds.push(u32::from_le_bytes([
+            journal_bytes[offset],
+            journal_bytes[offset + 1],
+            journal_bytes[offset + 2],
+            journal_bytes[offset + 3],
+        ]));
+    }
+
+    let all_valid = u32::from_le_bytes([
+        journal_bytes[seeds_end],
+        journal_bytes[seeds_end + 1],
+        journal_bytes[seeds_end + 2],
+        journal_bytes[seeds_end + 3],
+    ]) != 0;
+
+    tracing::info!("Journey proof generated successfully");
+
+    Ok(JourneyProof {
+        maze_seeds,
+        all_valid,
+        receipt,
+        receipt_kind,
+    })
+}
+
+/// Cache key for a `verify_path_proof`/`verify_path_proof_with_credential`
+/// call: the maze proof being verified against, the moves, the effective
+/// receipt kind, and (if present) the anonymous credential - two calls that
+/// only differ by credential must not share a cached journal/nullifier.
+fn verify_cache_key(
+    maze_proof: &MazeProof,
+    moves: &[u8],
+    receipt_kind: ReceiptKind,
+    credential: Option<&AnonymousCredential>,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let mut key = maze_proof.pack()?;
+    key.extend_from_slice(&(moves.len() as u32).to_le_bytes());
+    key.extend_from_slice(moves);
+    key.push(receipt_kind.as_tag());
+    match credential {
+        Some(cred) => {
+            key.push(1);
+            key.extend_from_slice(&cred.identity);
+            key.extend_from_slice(&cred.identity_root);
+        }
+        None => key.push(0),
+    }
+    Ok(key)
+}
+
+/// One item of a [`verify_path_proof_batch`] result: a single path proof
+/// plus its inclusion proof against [`BatchPathProof::root`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchPathProofItem {
+    pub path_proof: PathProof,
+    pub inclusion_proof: BatchInclusionProof,
+}
+
+/// Output from [`verify_path_proof_batch`]: every requested path proof,
+/// each paired with an inclusion proof against the single `root` that
+/// commits to the whole batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchPathProof {
+    pub root: [u8; 32],
+    pub items: Vec<BatchPathProofItem>,
+}
+
+/// Generate path proofs for a batch of `(maze_proof, moves)` requests and
+/// Merkle-aggregate their journals under one root, so a verifier can later
+/// check any single solution's membership with a small inclusion proof
+/// instead of re-checking every receipt in the batch.
+///
+/// Each leaf is `SHA256` of that proof's own committed [`maze_core`] path
+/// journal bytes (`is_valid` + `maze_seed`, then the `identity_root` +
+/// `nullifier_hash` extension) - see [`journal_merkle`].
+pub fn verify_path_proof_batch(
+    requests: Vec<(MazeProof, Vec<u8>)>,
+    receipt_kind_override: Option<ReceiptKind>,
+) -> Result<BatchPathProof, Box<dyn std::error::Error>> {
+    let mut path_proofs = Vec::with_capacity(requests.len());
+    for (maze_proof, moves) in requests {
+        path_proofs.push(verify_path_proof(&maze_proof, moves, receipt_kind_override)?);
+    }
+
+    let leaves: Vec<journal_merkle::Hash> = path_proofs
+        .iter()
+        .map(|p| journal_merkle::hash_leaf(&p.receipt.journal.bytes))
+        .collect();
+    let (root, inclusion_proofs) = journal_merkle::build(&leaves);
+
+    let items = path_proofs
+        .into_iter()
+        .zip(inclusion_proofs)
+        .map(|(path_proof, inclusion_proof)| BatchPathProofItem {
+            path_proof,
+            inclusion_proof,
+        })
+        .collect();
+
+    Ok(BatchPathProof { root, items })
+}
+
+/// Output from the Merkle-backed path verification proof (see
+/// [`verify_path_proof_merkle`])
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathMerkleProof {
+    /// Whether the path successfully reached the goal
+    pub is_valid: bool,
+
+    /// The seed of the maze this path was verified against
+    pub maze_seed: u32,
+
+    /// The Merkle root the opened cells were checked against
+    pub maze_root: [u8; 32],
+
+    /// The receipt proving path validity against `maze_root`
+    pub receipt: Receipt,
+
+    /// The type of receipt generated (composite, succinct, or groth16)
+    pub receipt_kind: ReceiptKind,
+}
+
+/// Generate a Merkle-backed path verification proof for a player's moves
+/// through a maze, given only its seed and Merkle root instead of the full
+/// `MazeProof`.
+///
+/// Regenerates the canonical full-size (`MAZE_ROWS` x `MAZE_COLS`, Minstd
+/// backend, unbraided) maze from `maze_seed` to derive its randomized
+/// start/goal cells and build a [`maze_core::MerkleGrid`] the same way
+/// [`Maze::merkle_grid`] always would for that seed, then checks the
+/// regenerated root against `maze_root` before proving anything - a cheap
+/// fail-fast host-side sanity check, since a mismatch means the guest could
+/// never open cells consistent with both.
+///
+/// The guest itself never sees the grid: for each cell the path visits, the
+/// host opens it via a [`maze_core::merkle_grid::verify_inclusion`] proof,
+/// stopping as soon as the path goes invalid or reaches the goal so a short
+/// valid path doesn't pay for openings past its own end.
+pub fn verify_path_proof_merkle(
+    maze_root: [u8; 32],
+    maze_seed: u32,
+    moves: Vec<u8>,
+    receipt_kind: ReceiptKind,
+) -> Result<PathMerkleProof, Box<dyn std::error::Error>> {
+    tracing::info!("Verifying Merkle path proof for maze seed {} with receipt kind: {}", maze_seed, receipt_kind);
+
+    let (maze, mut rng) = Maze::generate_with_rng(MAZE_ROWS, MAZE_COLS, maze_seed, RngBackend::Minstd, 0);
+    let (start_row, start_col, goal_row, goal_col) = maze.select_start_and_goal(&mut rng);
+
+    let merkle = maze.merkle_grid();
+    let computed_root = merkle.merkle_root();
+    if computed_root != maze_root {
+        return Err("Provided maze_root does not match the maze regenerated from maze_seed".into());
+    }
+
+    let grid = maze.to_binary_grid();
+    let grid_rows = MAZE_ROWS * 2 + 1;
+    let grid_cols = MAZE_COLS * 2 + 1;
+    let start = (start_row as usize * 2 + 1, start_col as usize * 2 + 1);
+    let goal = (goal_row as usize * 2 + 1, goal_col as usize * 2 + 1);
+
+    let move_count = moves.len().min(MAX_MOVES) as u16;
+
+    let mut builder = ExecutorEnv::builder();
+    builder.write(&maze_seed)?;
+    builder.write_slice(&maze_root);
+    builder.write(&(MAZE_ROWS as u8))?;
+    builder.write(&(MAZE_COLS as u8))?;
+    builder.write(&(start_row as u8))?;
+    builder.write(&(start_col as u8))?;
+    builder.write(&(goal_row as u8))?;
+    builder.write(&(goal_col as u8))?;
+    builder.write(&move_count)?;
+    builder.write_slice(&moves[..move_count as usize]);
+
+    // Walk the path exactly like the guest will, opening each visited cell
+    // against `merkle` and stopping as soon as it goes invalid or the goal
+    // is reached, so `proof_count` caps how many openings get shipped.
+    let mut openings = Vec::new();
+    let (mut row, mut col) = start;
+    openings.push((grid[row][col], merkle.inclusion_proof(row, col)));
+    let mut has_reached_end = row < grid_rows && col < grid_cols && (row, col) == goal;
+
+    if row < grid_rows && col < grid_cols {
+        for &direction in &moves[..move_count as usize] {
+            if has_reached_end || direction > 3 {
+                break;
+            }
+
+            let dir_idx = direction as usize;
+            const ROW_DELTAS: [i32; 4] = [-1, 0, 1, 0];
+            const COL_DELTAS: [i32; 4] = [0, 1, 0, -1];
+            let next_row = (row as i32).wrapping_add(ROW_DELTAS[dir_idx]) as usize;
+            let next_col = (col as i32).wrapping_add(COL_DELTAS[dir_idx]) as usize;
+            if next_row >= grid_rows || next_col >= grid_cols {
+                break;
+            }
+
+            openings.push((grid[next_row][next_col], merkle.inclusion_proof(next_row, next_col)));
+            if grid[next_row][next_col] != 1 {
+                break;
+            }
+
+            row = next_row;
+            col = next_col;
+            if (row, col) == goal {
+                has_reached_end = true;
+            }
+        }
+    }
+
+    // Cell openings are written directly (rather than through a shared
+    // helper) since `ExecutorEnvBuilder` borrows the input buffers it's
+    // given for the environment's lifetime, which a separate function
+    // taking `&mut builder` would need to name explicitly.
+    builder.write(&(openings.len() as u16))?;
+    for (value, proof) in &openings {
+        let (siblings, path_bits) = proof;
+        builder.write(value)?;
+        for sibling in siblings {
+            builder.write_slice(sibling);
+        }
+        builder.write(path_bits)?;
+    }
+
+    let env = builder.build()?;
+
+    let opts = match receipt_kind {
+        ReceiptKind::Composite => ProverOpts::composite(),
+        ReceiptKind::Succinct => ProverOpts::succinct(),
+        ReceiptKind::Groth16 => ProverOpts::groth16(),
+    };
+
+    tracing::info!("Generating Merkle path verification proof...");
+    let prover = default_prover();
+    let prove_info = prover
+        .prove_with_opts(env, PATH_VERIFY_MERKLE_ELF, &opts)
+        .map_err(|e| format!("Failed to generate Merkle path proof: {}", e))?;
+
+    let receipt = prove_info.receipt;
+
+    let journal_bytes = &receipt.journal.bytes;
+    if journal_bytes.len() < PATH_MERKLE_JOURNAL_SIZE {
+        return Err(format!(
+            "Journal too short: expected {} bytes, got {}",
+            PATH_MERKLE_JOURNAL_SIZE,
+            journal_bytes.len()
+        )
+        .into());
+    }
+
+    let is_valid_u32 = u32::from_le_bytes([journal_bytes[0], journal_bytes[1], journal_bytes[2], journal_bytes[3]]);
+    let maze_seed_out = u32::from_le_bytes([journal_bytes[4], journal_bytes[5], journal_bytes[6], journal_bytes[7]]);
+    let mut maze_root_out = [0u8; 32];
+    maze_root_out.copy_from_slice(&journal_bytes[8..8 + 32]);
+
+    tracing::info!("Merkle path proof generated successfully");
+
+    Ok(PathMerkleProof {
         is_valid: is_valid_u32 != 0,
         maze_seed: maze_seed_out,
+        maze_root: maze_root_out,
         receipt,
         receipt_kind,
     })
 }
 
+/// The `PATH_VERIFY_ID` method digest, as a [`risc0_zkvm::sha::Digest`].
+///
+/// Built once from the `[u32; 8]` image ID the same way `verify_path_proof`
+/// builds `MAZE_GEN_ID`'s digest bytes, so it can be compared directly
+/// against a receipt's claimed pre-state digest.
+fn expected_path_verify_image_id() -> risc0_zkvm::sha::Digest {
+    let mut bytes = [0u8; 32];
+    for (i, &word) in PATH_VERIFY_ID.iter().enumerate() {
+        bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+    }
+    risc0_zkvm::sha::Digest::try_from(bytes.as_slice())
+        .expect("PATH_VERIFY_ID is always a valid 32-byte digest")
+}
+
+/// Approximate serialized-size range for each receipt kind, in bytes.
+///
+/// Used as a cheap sanity check before running full cryptographic
+/// verification - wide enough to tolerate normal version-to-version growth,
+/// tight enough to reject a receipt whose shape obviously doesn't match its
+/// claimed kind (e.g. a multi-megabyte Composite receipt mislabeled as
+/// Groth16).
+fn expected_receipt_size_range(kind: ReceiptKind) -> (usize, usize) {
+    match kind {
+        ReceiptKind::Composite => (1_000, 50_000_000),
+        ReceiptKind::Succinct => (10_000, 5_000_000),
+        ReceiptKind::Groth16 => (100, 10_000),
+    }
+}
+
 /// Verify a PathProof receipt cryptographically.
 ///
 /// This function verifies that a PathProof's receipt is valid by checking:
-/// 1. The receipt is cryptographically valid (signature verification)
-/// 2. The receipt was generated by the PATH_VERIFY program (image ID check)
+/// 1. The receipt's claimed image ID is pinned to the expected PATH_VERIFY
+///    guest, and its serialized size matches the expected shape for its
+///    receipt kind - both cheap checks done before any crypto runs, so a
+///    receipt produced by a different (or tampered) guest program is
+///    rejected with a distinct error instead of being handed to the
+///    verifier at all
+/// 2. The receipt is cryptographically valid (signature verification)
+/// 3. The receipt was generated by the PATH_VERIFY program (image ID check,
+///    enforced again here by the verifier itself)
 ///
 /// # Arguments
 /// * `path_proof` - The path proof to verify
@@ -369,6 +1625,33 @@ pub fn verify_path_proof_receipt(
 ) -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Verifying path proof receipt for maze seed {}", path_proof.maze_seed);
 
+    // Pin the guest image ID before doing any expensive cryptographic
+    // verification, closing a substitution hole where a receipt that
+    // self-verifies but was produced by a different circuit would
+    // otherwise be accepted.
+    let claim = path_proof.receipt.claim()?.value()?;
+    let actual_image_id = claim.pre.digest();
+    let expected_image_id = expected_path_verify_image_id();
+    if actual_image_id != expected_image_id {
+        return Err(format!(
+            "Receipt image ID mismatch: expected {}, got {} - this receipt was not produced by the path-verify guest",
+            expected_image_id, actual_image_id
+        )
+        .into());
+    }
+
+    // Sanity-check the receipt's serialized size against the expected
+    // shape for its claimed receipt kind.
+    let serialized_len = serde_json::to_vec(&path_proof.receipt)?.len();
+    let (min_len, max_len) = expected_receipt_size_range(path_proof.receipt_kind);
+    if serialized_len < min_len || serialized_len > max_len {
+        return Err(format!(
+            "Receipt size {} bytes is outside the expected range for {} receipts ({}..={})",
+            serialized_len, path_proof.receipt_kind, min_len, max_len
+        )
+        .into());
+    }
+
     // Verify the receipt against the PATH_VERIFY image ID
     path_proof.receipt
         .verify(PATH_VERIFY_ID)
@@ -377,3 +1660,284 @@ pub fn verify_path_proof_receipt(
     tracing::info!("Receipt verification successful");
     Ok(())
 }
+
+/// Identical to [`verify_path_proof_receipt`], but additionally requires the
+/// proof to carry an anonymous credential whose `identity_root` is one of
+/// `accepted_identity_roots` - the set of eligible-player trees a verifier
+/// currently trusts. Rejecting an untrusted root here, rather than silently
+/// accepting any root the prover claims, is what makes the credential mean
+/// "a member of *this* eligible-player set" instead of "a member of some set
+/// or other".
+///
+/// Does not itself check `nullifier_hash` for reuse - that's a verifier-side
+/// concern (e.g. a set of seen nullifiers kept alongside the accepted
+/// roots), not something a single receipt can attest to.
+pub fn verify_path_proof_receipt_anonymous(
+    path_proof: &PathProof,
+    accepted_identity_roots: &[[u8; 32]],
+) -> Result<(), Box<dyn std::error::Error>> {
+    verify_path_proof_receipt(path_proof)?;
+
+    let identity_root = path_proof
+        .identity_root
+        .ok_or("Path proof does not carry an anonymous credential")?;
+
+    if !accepted_identity_roots.contains(&identity_root) {
+        return Err(format!(
+            "Identity root {:02x?} is not in the accepted set",
+            &identity_root[..4]
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The `MAZE_GEN_ID` method digest, as a [`risc0_zkvm::sha::Digest`].
+///
+/// Built the same way [`expected_path_verify_image_id`] builds
+/// `PATH_VERIFY_ID`'s.
+fn expected_maze_gen_image_id() -> risc0_zkvm::sha::Digest {
+    let mut bytes = [0u8; 32];
+    for (i, &word) in MAZE_GEN_ID.iter().enumerate() {
+        bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+    }
+    risc0_zkvm::sha::Digest::try_from(bytes.as_slice())
+        .expect("MAZE_GEN_ID is always a valid 32-byte digest")
+}
+
+/// Verify a `MazeProof` receipt cryptographically, mirroring
+/// [`verify_path_proof_receipt`]'s image ID pin and size sanity check
+/// before running full cryptographic verification.
+pub fn verify_maze_proof_receipt(
+    maze_proof: &MazeProof,
+) -> Result<(), Box<dyn std::error::Error>> {
+    tracing::info!("Verifying maze proof receipt for seed {}", maze_proof.maze_seed);
+
+    let claim = maze_proof.receipt.claim()?.value()?;
+    let actual_image_id = claim.pre.digest();
+    let expected_image_id = expected_maze_gen_image_id();
+    if actual_image_id != expected_image_id {
+        return Err(format!(
+            "Receipt image ID mismatch: expected {}, got {} - this receipt was not produced by the maze-gen guest",
+            expected_image_id, actual_image_id
+        )
+        .into());
+    }
+
+    let serialized_len = serde_json::to_vec(&maze_proof.receipt)?.len();
+    let (min_len, max_len) = expected_receipt_size_range(maze_proof.receipt_kind);
+    if serialized_len < min_len || serialized_len > max_len {
+        return Err(format!(
+            "Receipt size {} bytes is outside the expected range for {} receipts ({}..={})",
+            serialized_len, maze_proof.receipt_kind, min_len, max_len
+        )
+        .into());
+    }
+
+    maze_proof.receipt
+        .verify(MAZE_GEN_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    tracing::info!("Receipt verification successful");
+    Ok(())
+}
+
+/// The `PATH_VERIFY_JOURNEY_ID` method digest, as a
+/// [`risc0_zkvm::sha::Digest`]. Built the same way
+/// [`expected_path_verify_image_id`] builds `PATH_VERIFY_ID`'s.
+fn expected_journey_image_id() -> risc0_zkvm::sha::Digest {
+    let mut bytes = [0u8; 32];
+    for (i, &word) in PATH_VERIFY_JOURNEY_ID.iter().enumerate() {
+        bytes[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+    }
+    risc0_zkvm::sha::Digest::try_from(bytes.as_slice())
+        .expect("PATH_VERIFY_JOURNEY_ID is always a valid 32-byte digest")
+}
+
+/// Verify a [`JourneyProof`] receipt cryptographically, mirroring
+/// [`verify_path_proof_receipt`]'s image ID pin and size sanity check before
+/// running full cryptographic verification.
+pub fn verify_journey_proof_receipt(
+    journey_proof: &JourneyProof,
+) -> Result<(), Box<dyn std::error::Error>> {
+    tracing::info!(
+        "Verifying journey proof receipt for {} mazes",
+        journey_proof.maze_seeds.len()
+    );
+
+    let claim = journey_proof.receipt.claim()?.value()?;
+    let actual_image_id = claim.pre.digest();
+    let expected_image_id = expected_journey_image_id();
+    if actual_image_id != expected_image_id {
+        return Err(format!(
+            "Receipt image ID mismatch: expected {}, got {} - this receipt was not produced by the path-verify-journey guest",
+            expected_image_id, actual_image_id
+        )
+        .into());
+    }
+
+    let serialized_len = serde_json::to_vec(&journey_proof.receipt)?.len();
+    let (min_len, max_len) = expected_receipt_size_range(journey_proof.receipt_kind);
+    if serialized_len < min_len || serialized_len > max_len {
+        return Err(format!(
+            "Receipt size {} bytes is outside the expected range for {} receipts ({}..={})",
+            serialized_len, journey_proof.receipt_kind, min_len, max_len
+        )
+        .into());
+    }
+
+    journey_proof.receipt
+        .verify(PATH_VERIFY_JOURNEY_ID)
+        .map_err(|e| format!("Receipt verification failed: {}", e))?;
+
+    tracing::info!("Receipt verification successful");
+    Ok(())
+}
+
+/// One receipt queued into a [`BatchValidator`] - either a path proof or a
+/// maze proof, each verified against its own guest image ID.
+enum QueuedReceipt<'a> {
+    Path(&'a PathProof),
+    Maze(&'a MazeProof),
+}
+
+impl QueuedReceipt<'_> {
+    fn receipt(&self) -> &Receipt {
+        match self {
+            QueuedReceipt::Path(p) => &p.receipt,
+            QueuedReceipt::Maze(p) => &p.receipt,
+        }
+    }
+
+    fn receipt_kind(&self) -> ReceiptKind {
+        match self {
+            QueuedReceipt::Path(p) => p.receipt_kind,
+            QueuedReceipt::Maze(p) => p.receipt_kind,
+        }
+    }
+
+    fn verify(&self) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            QueuedReceipt::Path(p) => verify_path_proof_receipt(p),
+            QueuedReceipt::Maze(p) => verify_maze_proof_receipt(p),
+        }
+    }
+}
+
+/// Which indices queued into a [`BatchValidator`] failed verification, and
+/// why.
+#[derive(Debug)]
+pub struct BatchError {
+    pub failures: Vec<(usize, String)>,
+}
+
+impl std::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} of the batch's receipts failed verification:", self.failures.len())?;
+        for (index, reason) in &self.failures {
+            write!(f, "\n  [{}] {}", index, reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+/// Accumulates `PathProof` (and optionally `MazeProof`) receipts queued by
+/// [`BatchValidator::queue`]/[`BatchValidator::queue_maze`] and verifies
+/// them all in [`BatchValidator::validate`] in parallel rather than one at a
+/// time on the caller's thread.
+///
+/// Queued receipts are grouped by `(image ID, ReceiptKind)`, and each group
+/// is handed its own native thread so independent groups verify
+/// concurrently; within a group, receipts are still verified one at a time
+/// via the same [`verify_path_proof_receipt`]/[`verify_maze_proof_receipt`]
+/// call a caller would make directly - there's no shared or amortized
+/// cryptographic work across a group, only wall-clock parallelism.
+/// `Composite` receipts are verified sequentially on the calling thread
+/// instead of being handed a thread of their own, since each is its own
+/// segment chain with nothing to parallelize within the group.
+#[derive(Default)]
+pub struct BatchValidator<'a> {
+    queued: Vec<QueuedReceipt<'a>>,
+}
+
+impl<'a> BatchValidator<'a> {
+    pub fn new() -> Self {
+        Self { queued: Vec::new() }
+    }
+
+    /// Queue a path proof for verification.
+    pub fn queue(&mut self, proof: &'a PathProof) {
+        self.queued.push(QueuedReceipt::Path(proof));
+    }
+
+    /// Queue a maze proof for verification.
+    pub fn queue_maze(&mut self, proof: &'a MazeProof) {
+        self.queued.push(QueuedReceipt::Maze(proof));
+    }
+
+    /// Verify every queued receipt, returning which indices (in queue
+    /// order, path and maze proofs sharing one index space) failed and why.
+    pub fn validate(self) -> Result<(), BatchError> {
+        let mut groups: std::collections::HashMap<([u8; 32], ReceiptKind), Vec<usize>> =
+            std::collections::HashMap::new();
+        let mut failures: Vec<(usize, String)> = Vec::new();
+
+        for (index, queued) in self.queued.iter().enumerate() {
+            match queued.receipt().claim().and_then(|c| c.value()) {
+                Ok(claim) => {
+                    let image_id: [u8; 32] = claim.pre.digest().as_bytes()
+                        .try_into()
+                        .expect("digest is 32 bytes");
+                    groups.entry((image_id, queued.receipt_kind())).or_default().push(index);
+                }
+                Err(e) => failures.push((index, format!("failed to read receipt claim: {}", e))),
+            }
+        }
+
+        let (composite_groups, parallel_groups): (Vec<_>, Vec<_>) = groups
+            .into_iter()
+            .partition(|((_, kind), _)| *kind == ReceiptKind::Composite);
+
+        for (_, indices) in composite_groups {
+            for index in indices {
+                if let Err(e) = self.queued[index].verify() {
+                    failures.push((index, e.to_string()));
+                }
+            }
+        }
+
+        let queued = &self.queued;
+        let parallel_failures: Vec<(usize, String)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = parallel_groups
+                .into_iter()
+                .map(|(_, indices)| {
+                    scope.spawn(move || {
+                        indices
+                            .into_iter()
+                            .filter_map(|index| {
+                                queued[index].verify().err().map(|e| (index, e.to_string()))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("verification thread panicked"))
+                .collect()
+        });
+        failures.extend(parallel_failures);
+
+        failures.sort_by_key(|(index, _)| *index);
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError { failures })
+        }
+    }
+}
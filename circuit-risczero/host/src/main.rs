@@ -1,8 +1,133 @@
-use host::{generate_maze_proof, verify_path_proof, verify_path_proof_receipt, MazeProof, PathProof, ReceiptKind};
+use host::{
+    bench::ConfidenceInterval, cache, generate_maze_proof, verify_inclusion, verify_path_proof,
+    verify_path_proof_receipt, InclusionProof, MazeProof, MmrAccumulator, PathProof, ReceiptKind,
+};
 use std::env;
 use std::fs;
+use std::io::{self, Write};
 use std::time::Instant;
 
+/// Output file format for saved proofs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProofFormat {
+    /// Pretty-printed JSON (default, human-readable)
+    Json,
+    /// Compact binary layout produced by `MazeProof::pack`/`PathProof::pack`
+    Packed,
+    /// MessagePack encoding of the same struct shape as JSON - without the
+    /// Groth16-only restriction `PathProof::pack` has; like `Packed`, it
+    /// still embeds the full serde `Receipt` shape
+    MsgPack,
+}
+
+impl Default for ProofFormat {
+    fn default() -> Self {
+        ProofFormat::Json
+    }
+}
+
+impl std::str::FromStr for ProofFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ProofFormat::Json),
+            "packed" => Ok(ProofFormat::Packed),
+            "msgpack" => Ok(ProofFormat::MsgPack),
+            _ => Err(format!("Invalid format: '{}'. Must be 'json', 'packed', or 'msgpack'", s)),
+        }
+    }
+}
+
+/// Parse the `--receipt-type <type>`, `--format <format>`, `--no-cache`, and
+/// trailing positional output file from a command's remaining arguments, in
+/// any order. Exits the process with a usage error on an unknown flag or a
+/// flag missing its value.
+fn parse_trailing_args(args: &[String]) -> (Option<String>, Option<String>, bool, Option<String>) {
+    let mut receipt_type = None;
+    let mut format = None;
+    let mut no_cache = false;
+    let mut output_file = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--receipt-type" => {
+                receipt_type = Some(args.get(i + 1).cloned().unwrap_or_else(|| {
+                    eprintln!("❌ Error: --receipt-type requires a value (composite|succinct|groth16)");
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            "--format" => {
+                format = Some(args.get(i + 1).cloned().unwrap_or_else(|| {
+                    eprintln!("❌ Error: --format requires a value (json|packed|msgpack)");
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            "--no-cache" => {
+                no_cache = true;
+                i += 1;
+            }
+            other if other.starts_with("--") => {
+                eprintln!("❌ Error: Unknown option '{}'", other);
+                std::process::exit(1);
+            }
+            other => {
+                output_file = Some(other.to_string());
+                i += 1;
+            }
+        }
+    }
+
+    (receipt_type, format, no_cache, output_file)
+}
+
+/// Parse the `--receipt-type <type>` and `--runs <N>` flags for a `bench`
+/// subcommand, in any order. `--runs` is required; exits with a usage
+/// error if it's missing, not a positive integer, or an unknown flag is
+/// passed.
+fn parse_bench_args(args: &[String]) -> (Option<String>, usize) {
+    let mut receipt_type = None;
+    let mut runs = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--receipt-type" => {
+                receipt_type = Some(args.get(i + 1).cloned().unwrap_or_else(|| {
+                    eprintln!("❌ Error: --receipt-type requires a value (composite|succinct|groth16)");
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            "--runs" => {
+                let value = args.get(i + 1).cloned().unwrap_or_else(|| {
+                    eprintln!("❌ Error: --runs requires a value");
+                    std::process::exit(1);
+                });
+                runs = Some(value.parse::<usize>().ok().filter(|&n| n > 0).unwrap_or_else(|| {
+                    eprintln!("❌ Error: --runs must be a positive integer, got '{}'", value);
+                    std::process::exit(1);
+                }));
+                i += 2;
+            }
+            other => {
+                eprintln!("❌ Error: Unknown argument '{}'", other);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let runs = runs.unwrap_or_else(|| {
+        eprintln!("❌ Error: --runs <N> is required");
+        std::process::exit(1);
+    });
+
+    (receipt_type, runs)
+}
+
 fn main() {
     // Initialize tracing for debug output
     tracing_subscriber::fmt()
@@ -29,7 +154,7 @@ fn main() {
     match command.as_str() {
         "generate-maze" => {
             if args.len() < 3 {
-                eprintln!("Usage: {} generate-maze <maze_seed> [--receipt-type <type>] [output_file]", args[0]);
+                eprintln!("Usage: {} generate-maze <maze_seed> [--receipt-type <type>] [--format <format>] [--no-cache] [output_file]", args[0]);
                 std::process::exit(1);
             }
 
@@ -38,30 +163,32 @@ fn main() {
                 std::process::exit(1);
             });
 
-            // Parse optional --receipt-type flag
-            let mut receipt_kind = ReceiptKind::default();
-            let mut output_file_idx = 3;
+            let (receipt_type, format, no_cache, output_file) = parse_trailing_args(&args[3..]);
 
-            if args.len() > 3 && args[3] == "--receipt-type" {
-                if args.len() < 5 {
-                    eprintln!("❌ Error: --receipt-type requires a value (composite|succinct|groth16)");
+            let receipt_kind = receipt_type
+                .map(|s| s.parse().unwrap_or_else(|e| {
+                    eprintln!("❌ Error: {}", e);
                     std::process::exit(1);
-                }
-                receipt_kind = args[4].parse().unwrap_or_else(|e| {
+                }))
+                .unwrap_or_default();
+
+            let format: ProofFormat = format
+                .map(|s| s.parse().unwrap_or_else(|e| {
                     eprintln!("❌ Error: {}", e);
                     std::process::exit(1);
-                });
-                output_file_idx = 5;
-            }
+                }))
+                .unwrap_or_default();
 
-            let output_file = args.get(output_file_idx).map(|s| s.as_str());
+            if no_cache {
+                cache::set_enabled(false);
+            }
 
-            generate_maze_command(maze_seed, receipt_kind, output_file);
+            generate_maze_command(maze_seed, receipt_kind, format, output_file.as_deref());
         }
 
         "verify-path" => {
             if args.len() < 4 {
-                eprintln!("Usage: {} verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] [output_file]", args[0]);
+                eprintln!("Usage: {} verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] [--format <format>] [--no-cache] [output_file]", args[0]);
                 eprintln!("Error: Missing required arguments");
                 std::process::exit(1);
             }
@@ -69,25 +196,25 @@ fn main() {
             let maze_proof_file = &args[2];
             let moves_file = &args[3];
 
-            // Parse optional --receipt-type flag
-            let mut receipt_kind = None;
-            let mut output_file_idx = 4;
+            let (receipt_type, format, no_cache, output_file) = parse_trailing_args(&args[4..]);
 
-            if args.len() > 4 && args[4] == "--receipt-type" {
-                if args.len() < 6 {
-                    eprintln!("❌ Error: --receipt-type requires a value (composite|succinct|groth16)");
-                    std::process::exit(1);
-                }
-                receipt_kind = Some(args[5].parse().unwrap_or_else(|e| {
+            let receipt_kind = receipt_type.map(|s| s.parse().unwrap_or_else(|e| {
+                eprintln!("❌ Error: {}", e);
+                std::process::exit(1);
+            }));
+
+            let format: ProofFormat = format
+                .map(|s| s.parse().unwrap_or_else(|e| {
                     eprintln!("❌ Error: {}", e);
                     std::process::exit(1);
-                }));
-                output_file_idx = 6;
-            }
+                }))
+                .unwrap_or_default();
 
-            let output_file = args.get(output_file_idx).map(|s| s.as_str());
+            if no_cache {
+                cache::set_enabled(false);
+            }
 
-            verify_path_command(maze_proof_file, moves_file, receipt_kind, output_file);
+            verify_path_command(maze_proof_file, moves_file, receipt_kind, format, output_file.as_deref());
         }
 
         "verify-proof" => {
@@ -102,6 +229,109 @@ fn main() {
             verify_proof_command(path_proof_file);
         }
 
+        "share-proof" => {
+            if args.len() < 3 {
+                eprintln!("Usage: {} share-proof <path_proof_file>", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            share_proof_command(&args[2]);
+        }
+
+        "import-proof" => {
+            if args.len() < 3 {
+                eprintln!("Usage: {} import-proof <token>", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            import_proof_command(&args[2]);
+        }
+
+        "aggregate" => {
+            if args.len() < 3 {
+                eprintln!("Usage: {} aggregate <path_proof_file>...", args[0]);
+                eprintln!("Error: Missing required argument");
+                std::process::exit(1);
+            }
+
+            aggregate_command(&args[2..]);
+        }
+
+        "verify-inclusion" => {
+            if args.len() < 4 {
+                eprintln!("Usage: {} verify-inclusion <root_hex> <inclusion_proof_file>", args[0]);
+                eprintln!("Error: Missing required arguments");
+                std::process::exit(1);
+            }
+
+            verify_inclusion_command(&args[2], &args[3]);
+        }
+
+        "cache" => {
+            if args.len() < 3 || args[2] != "gc" {
+                eprintln!("Usage: {} cache gc", args[0]);
+                eprintln!("Error: Missing or unknown cache subcommand");
+                std::process::exit(1);
+            }
+
+            cache_gc_command();
+        }
+
+        "bench" => {
+            if args.len() < 3 {
+                eprintln!("Usage: {} bench <generate-maze|verify-path> ... --runs <N>", args[0]);
+                std::process::exit(1);
+            }
+
+            match args[2].as_str() {
+                "generate-maze" => {
+                    if args.len() < 4 {
+                        eprintln!("Usage: {} bench generate-maze <maze_seed> [--receipt-type <type>] --runs <N>", args[0]);
+                        std::process::exit(1);
+                    }
+
+                    let maze_seed: u32 = args[3].parse().unwrap_or_else(|_| {
+                        eprintln!("❌ Error: Invalid maze seed '{}'. Must be a positive integer.", args[3]);
+                        std::process::exit(1);
+                    });
+
+                    let (receipt_type, runs) = parse_bench_args(&args[4..]);
+                    let receipt_kind = receipt_type.map(|s| s.parse().unwrap_or_else(|e| {
+                        eprintln!("❌ Error: {}", e);
+                        std::process::exit(1);
+                    }));
+
+                    bench_generate_maze_command(maze_seed, receipt_kind, runs);
+                }
+
+                "verify-path" => {
+                    if args.len() < 5 {
+                        eprintln!("Usage: {} bench verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] --runs <N>", args[0]);
+                        std::process::exit(1);
+                    }
+
+                    let maze_proof_file = &args[3];
+                    let moves_file = &args[4];
+
+                    let (receipt_type, runs) = parse_bench_args(&args[5..]);
+                    let receipt_kind = receipt_type.map(|s| s.parse().unwrap_or_else(|e| {
+                        eprintln!("❌ Error: {}", e);
+                        std::process::exit(1);
+                    }));
+
+                    bench_verify_path_command(maze_proof_file, moves_file, receipt_kind, runs);
+                }
+
+                other => {
+                    eprintln!("❌ Unknown bench target: {}", other);
+                    eprintln!("Usage: {} bench <generate-maze|verify-path> ... --runs <N>", args[0]);
+                    std::process::exit(1);
+                }
+            }
+        }
+
         _ => {
             eprintln!("❌ Unknown command: {}", command);
             print_usage(&args[0]);
@@ -114,27 +344,60 @@ fn print_usage(program: &str) {
     eprintln!("Usage: {} <command> [options]", program);
     eprintln!();
     eprintln!("Commands:");
-    eprintln!("  generate-maze <seed> [--receipt-type <type>] [output_file]");
+    eprintln!("  generate-maze <seed> [--receipt-type <type>] [--format <format>] [--no-cache] [output_file]");
     eprintln!("      Generate a maze proof from a seed");
     eprintln!("      - seed: Integer seed for maze generation");
     eprintln!("      - --receipt-type: Optional receipt type (composite|succinct|groth16)");
     eprintln!("                        Default: succinct");
-    eprintln!("      - output_file: Optional file to save the maze proof (JSON)");
-    eprintln!("                     Defaults to: <seed>_maze_proof.json");
+    eprintln!("      - --format: Optional output format (json|packed|msgpack). Default: json");
+    eprintln!("                  packed is a compact binary layout for network/on-chain transport");
+    eprintln!("                  msgpack is a compact encoding of the same struct shape as json");
+    eprintln!("      - --no-cache: Skip the .zkp-maze-cache/ proof cache and always re-prove");
+    eprintln!("      - output_file: Optional file to save the maze proof");
+    eprintln!("                     Defaults to: <seed>_maze_proof.json (.bin if packed, .msgpack if msgpack)");
     eprintln!();
-    eprintln!("  verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] [output_file]");
+    eprintln!("  verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] [--format <format>] [--no-cache] [output_file]");
     eprintln!("      Generate a path verification proof");
-    eprintln!("      - maze_proof_file: JSON file containing the maze proof");
+    eprintln!("      - maze_proof_file: File containing the maze proof (json, packed, or msgpack, auto-detected)");
     eprintln!("      - moves_file: JSON file containing the moves array");
     eprintln!("      - --receipt-type: Optional receipt type override (composite|succinct|groth16)");
     eprintln!("                        If not provided, auto-detects from maze_proof");
     eprintln!("                        Useful for succinct maze → groth16 path compression");
-    eprintln!("      - output_file: Optional file to save the path proof (JSON)");
-    eprintln!("                     Defaults to: <seed>_path_proof.json");
+    eprintln!("      - --format: Optional output format (json|packed|msgpack). Default: json");
+    eprintln!("                  packed requires a Groth16 receipt");
+    eprintln!("      - --no-cache: Skip the .zkp-maze-cache/ proof cache and always re-prove");
+    eprintln!("      - output_file: Optional file to save the path proof");
+    eprintln!("                     Defaults to: <seed>_path_proof.json (.bin if packed, .msgpack if msgpack)");
     eprintln!();
     eprintln!("  verify-proof <path_proof_file>");
     eprintln!("      Cryptographically verify a path proof receipt");
-    eprintln!("      - path_proof_file: JSON file containing the path proof");
+    eprintln!("      - path_proof_file: File containing the path proof (json, packed, or msgpack, auto-detected)");
+    eprintln!();
+    eprintln!("  share-proof <path_proof_file>");
+    eprintln!("      Encode a Groth16 path proof as a self-verifying Base58Check token");
+    eprintln!("      - path_proof_file: File containing the path proof (json, packed, or msgpack, auto-detected)");
+    eprintln!("      - requires a Groth16 receipt");
+    eprintln!();
+    eprintln!("  import-proof <token>");
+    eprintln!("      Decode a Base58Check token produced by 'share-proof' back into a path proof");
+    eprintln!();
+    eprintln!("  aggregate <path_proof_file>...");
+    eprintln!("      Commit many path proofs into one Merkle Mountain Range");
+    eprintln!("      - prints the MMR root to publish");
+    eprintln!("      - saves an inclusion proof per input file as '<file>.inclusion.json'");
+    eprintln!();
+    eprintln!("  verify-inclusion <root_hex> <inclusion_proof_file>");
+    eprintln!("      Check a single inclusion proof against a published MMR root");
+    eprintln!("      - root_hex: 64 hex characters (the root printed by 'aggregate')");
+    eprintln!();
+    eprintln!("  cache gc");
+    eprintln!("      Delete every entry in the .zkp-maze-cache/ proof cache");
+    eprintln!();
+    eprintln!("  bench generate-maze <seed> [--receipt-type <type>] --runs <N>");
+    eprintln!("  bench verify-path <maze_proof_file> <moves_file> [--receipt-type <type>] --runs <N>");
+    eprintln!("      Repeat proof generation N times and report a 95% confidence interval");
+    eprintln!("      on the proving time, per receipt kind (all three if --receipt-type is omitted)");
+    eprintln!("      - always bypasses the .zkp-maze-cache/ cache so every run actually proves");
     eprintln!();
     eprintln!("Receipt Types:");
     eprintln!("  composite: Fastest proving, largest size (~MB)");
@@ -149,7 +412,7 @@ fn print_usage(program: &str) {
     eprintln!("  3. Verify proof:   {} verify-proof 2918957128_path_proof.json", program);
 }
 
-fn generate_maze_command(maze_seed: u32, receipt_kind: ReceiptKind, output_file: Option<&str>) {
+fn generate_maze_command(maze_seed: u32, receipt_kind: ReceiptKind, format: ProofFormat, output_file: Option<&str>) {
     println!("📋 Generating maze proof");
     println!("  Maze seed: {}", maze_seed);
     println!("  Receipt type: {}", receipt_kind);
@@ -170,7 +433,7 @@ fn generate_maze_command(maze_seed: u32, receipt_kind: ReceiptKind, output_file:
                      maze_proof.grid_hash[1],
                      maze_proof.grid_hash[2],
                      maze_proof.grid_hash[3]);
-            println!("  Journal size: {} bytes (seed + hash) - 97.9% smaller!",
+            println!("  Journal size: {} bytes (seed + hash + metadata) - 97.3% smaller!",
                      maze_proof.receipt.journal.bytes.len());
             println!("  Grid size: {}x{} cells",
                      maze_proof.grid_data.len(),
@@ -178,10 +441,15 @@ fn generate_maze_command(maze_seed: u32, receipt_kind: ReceiptKind, output_file:
             println!();
 
             // Use default filename pattern if no output file specified
-            let default_filename = format!("{}_maze_proof.json", maze_seed);
+            let default_ext = match format {
+                ProofFormat::Packed => "bin",
+                ProofFormat::MsgPack => "msgpack",
+                ProofFormat::Json => "json",
+            };
+            let default_filename = format!("{}_maze_proof.{}", maze_seed, default_ext);
             let file_to_save = output_file.unwrap_or(&default_filename);
 
-            match save_maze_proof(&maze_proof, file_to_save) {
+            match save_maze_proof(&maze_proof, file_to_save, format) {
                 Ok(_) => {
                     println!("💾 Maze proof saved to: {}", file_to_save);
                     println!("   Share this file with players to verify their paths!");
@@ -203,7 +471,7 @@ fn generate_maze_command(maze_seed: u32, receipt_kind: ReceiptKind, output_file:
     }
 }
 
-fn verify_path_command(maze_proof_file: &str, moves_file: &str, receipt_kind: Option<ReceiptKind>, output_file: Option<&str>) {
+fn verify_path_command(maze_proof_file: &str, moves_file: &str, receipt_kind: Option<ReceiptKind>, format: ProofFormat, output_file: Option<&str>) {
     println!("📋 Generating path verification proof");
     println!("  Maze proof file: {}", maze_proof_file);
     println!("  Moves file: {}", moves_file);
@@ -252,10 +520,15 @@ fn verify_path_command(maze_proof_file: &str, moves_file: &str, receipt_kind: Op
             println!();
 
             // Use default filename pattern if no output file specified
-            let default_filename = format!("{}_path_proof.json", path_proof.maze_seed);
+            let default_ext = match format {
+                ProofFormat::Packed => "bin",
+                ProofFormat::MsgPack => "msgpack",
+                ProofFormat::Json => "json",
+            };
+            let default_filename = format!("{}_path_proof.{}", path_proof.maze_seed, default_ext);
             let file_to_save = output_file.unwrap_or(&default_filename);
 
-            match save_path_proof(&path_proof, file_to_save) {
+            match save_path_proof(&path_proof, file_to_save, format) {
                 Ok(_) => {
                     println!("💾 Path proof saved to: {}", file_to_save);
                     println!("   Use 'verify-proof {}' to cryptographically verify this proof", file_to_save);
@@ -330,28 +603,338 @@ fn verify_proof_command(path_proof_file: &str) {
     }
 }
 
-fn save_maze_proof(maze_proof: &MazeProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let json = serde_json::to_string_pretty(maze_proof)?;
-    fs::write(path, json)?;
+fn share_proof_command(path_proof_file: &str) {
+    println!("📋 Sharing path proof");
+    println!("  Path proof file: {}", path_proof_file);
+    println!();
+
+    let path_proof = match load_path_proof(path_proof_file) {
+        Ok(proof) => proof,
+        Err(e) => {
+            eprintln!("❌ Error loading path proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    match path_proof.share() {
+        Ok(token) => {
+            println!("✅ Share this token with players to verify their path:");
+            println!();
+            println!("{}", token);
+        }
+        Err(e) => {
+            eprintln!("❌ Error packing path proof: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn import_proof_command(token: &str) {
+    println!("📋 Importing path proof token");
+    println!();
+
+    match PathProof::import(token) {
+        Ok(path_proof) => {
+            println!("✅ Token decoded and checksum verified!");
+            println!("  Seed: {}", path_proof.maze_seed);
+            println!("  Path valid: {}", if path_proof.is_valid { "Yes ✓" } else { "No ✗" });
+        }
+        Err(e) => {
+            eprintln!("❌ Error importing token: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn aggregate_command(path_proof_files: &[String]) {
+    println!("📋 Aggregating {} path proofs into a Merkle Mountain Range", path_proof_files.len());
+    println!();
+
+    let mut mmr = MmrAccumulator::new();
+    let mut loaded_files = Vec::with_capacity(path_proof_files.len());
+
+    for file in path_proof_files {
+        match load_path_proof(file) {
+            Ok(proof) => {
+                mmr.append(&proof);
+                loaded_files.push(file.clone());
+            }
+            Err(e) => {
+                eprintln!("❌ Error loading path proof '{}': {}", file, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let root = mmr.root();
+    println!("✅ MMR root ({} leaves): {}", mmr.leaf_count(), to_hex(&root));
+    println!();
+
+    for (i, file) in loaded_files.iter().enumerate() {
+        let inclusion = match mmr.prove_inclusion(i) {
+            Ok(inclusion) => inclusion,
+            Err(e) => {
+                eprintln!("❌ Error building inclusion proof for '{}': {}", file, e);
+                std::process::exit(1);
+            }
+        };
+
+        let out_path = format!("{}.inclusion.json", file);
+        match serde_json::to_string_pretty(&inclusion) {
+            Ok(json) => {
+                if let Err(e) = fs::write(&out_path, json) {
+                    eprintln!("❌ Error saving inclusion proof for '{}': {}", file, e);
+                    std::process::exit(1);
+                }
+                println!("  {} -> {}", file, out_path);
+            }
+            Err(e) => {
+                eprintln!("❌ Error serializing inclusion proof for '{}': {}", file, e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    println!("{}", "=".repeat(70));
+}
+
+fn verify_inclusion_command(root_hex: &str, inclusion_proof_file: &str) {
+    println!("📋 Verifying inclusion against MMR root");
+    println!("  Root: {}", root_hex);
+    println!("  Inclusion proof file: {}", inclusion_proof_file);
+    println!();
+
+    let root = match parse_hex_hash(root_hex) {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let json = match fs::read_to_string(inclusion_proof_file) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("❌ Error reading inclusion proof file: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let inclusion: InclusionProof = match serde_json::from_str(&json) {
+        Ok(inclusion) => inclusion,
+        Err(e) => {
+            eprintln!("❌ Error parsing inclusion proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if verify_inclusion(root, &inclusion) {
+        println!("✅ Leaf {} is included under this MMR root!", inclusion.leaf_index);
+    } else {
+        eprintln!("❌ Inclusion check failed - leaf is NOT part of this MMR root");
+        std::process::exit(1);
+    }
+}
+
+/// Receipt kinds to sweep when `--receipt-type` isn't given to `bench`, in
+/// cheapest-to-proof-size order so the comparison prints composite first.
+const ALL_RECEIPT_KINDS: [ReceiptKind; 3] = [ReceiptKind::Composite, ReceiptKind::Succinct, ReceiptKind::Groth16];
+
+fn print_confidence_interval(label: &str, durations: &[std::time::Duration]) {
+    match ConfidenceInterval::from_samples(durations) {
+        Some(ci) => println!(
+            "    {}: {:.3}s ± {:.3}s (95% CI, n={})",
+            label, ci.mean_secs, ci.margin_secs, ci.runs
+        ),
+        None => println!("    {}: no samples collected", label),
+    }
+}
+
+fn bench_generate_maze_command(maze_seed: u32, receipt_kind: Option<ReceiptKind>, runs: usize) {
+    println!("📋 Benchmarking maze proof generation");
+    println!("  Maze seed: {}", maze_seed);
+    println!("  Runs per receipt kind: {}", runs);
+    println!();
+
+    // Every run must actually prove, or run 2..N would just be cache hits.
+    cache::set_enabled(false);
+
+    let kinds: Vec<ReceiptKind> = receipt_kind.map_or_else(|| ALL_RECEIPT_KINDS.to_vec(), |kind| vec![kind]);
+
+    for kind in kinds {
+        print!("  {}: ", kind);
+        io::stdout().flush().ok();
+
+        let mut durations = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let start = Instant::now();
+            if let Err(e) = generate_maze_proof(maze_seed, kind) {
+                eprintln!();
+                eprintln!("❌ Error generating maze proof ({}): {}", kind, e);
+                std::process::exit(1);
+            }
+            durations.push(start.elapsed());
+            print!(".");
+            io::stdout().flush().ok();
+        }
+        println!();
+
+        print_confidence_interval(&kind.to_string(), &durations);
+    }
+
+    println!("{}", "=".repeat(70));
+}
+
+fn bench_verify_path_command(maze_proof_file: &str, moves_file: &str, receipt_kind: Option<ReceiptKind>, runs: usize) {
+    println!("📋 Benchmarking path verification proof generation");
+    println!("  Maze proof file: {}", maze_proof_file);
+    println!("  Moves file: {}", moves_file);
+    println!("  Runs per receipt kind: {}", runs);
+    println!();
+
+    let maze_proof = match load_maze_proof(maze_proof_file) {
+        Ok(proof) => proof,
+        Err(e) => {
+            eprintln!("❌ Error loading maze proof: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let moves = match load_moves(moves_file) {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("❌ Error loading moves: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Every run must actually prove, or run 2..N would just be cache hits.
+    cache::set_enabled(false);
+
+    let kinds: Vec<ReceiptKind> = receipt_kind.map_or_else(|| ALL_RECEIPT_KINDS.to_vec(), |kind| vec![kind]);
+
+    for kind in kinds {
+        print!("  {}: ", kind);
+        io::stdout().flush().ok();
+
+        let mut durations = Vec::with_capacity(runs);
+        for _ in 0..runs {
+            let start = Instant::now();
+            if let Err(e) = verify_path_proof(&maze_proof, moves.clone(), Some(kind)) {
+                eprintln!();
+                eprintln!("❌ Error generating path proof ({}): {}", kind, e);
+                std::process::exit(1);
+            }
+            durations.push(start.elapsed());
+            print!(".");
+            io::stdout().flush().ok();
+        }
+        println!();
+
+        print_confidence_interval(&kind.to_string(), &durations);
+    }
+
+    println!("{}", "=".repeat(70));
+}
+
+fn cache_gc_command() {
+    println!("📋 Pruning proof cache");
+    println!();
+
+    match cache::gc() {
+        Ok((entries, bytes)) => {
+            println!("✅ Removed {} cached entr{} ({} bytes)", entries, if entries == 1 { "y" } else { "ies" }, bytes);
+        }
+        Err(e) => {
+            eprintln!("❌ Error pruning proof cache: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn parse_hex_hash(s: &str) -> Result<[u8; 32], String> {
+    if s.len() != 64 {
+        return Err(format!("Expected 64 hex characters, got {}", s.len()));
+    }
+
+    let mut hash = [0u8; 32];
+    for i in 0..32 {
+        hash[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16)
+            .map_err(|_| format!("Invalid hex digit at position {}", i * 2))?;
+    }
+    Ok(hash)
+}
+
+fn save_maze_proof(maze_proof: &MazeProof, path: &str, format: ProofFormat) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        ProofFormat::Json => {
+            let json = serde_json::to_string_pretty(maze_proof)?;
+            fs::write(path, json)?;
+        }
+        ProofFormat::Packed => {
+            fs::write(path, maze_proof.pack()?)?;
+        }
+        ProofFormat::MsgPack => {
+            fs::write(path, rmp_serde::to_vec(maze_proof)?)?;
+        }
+    }
     Ok(())
 }
 
+/// Load a maze proof, auto-detecting whether `path` holds a
+/// `MazeProof::pack` blob (by magic bytes), JSON, or MessagePack.
 fn load_maze_proof(path: &str) -> Result<MazeProof, Box<dyn std::error::Error>> {
-    let json = fs::read_to_string(path)?;
-    let maze_proof: MazeProof = serde_json::from_str(&json)?;
-    Ok(maze_proof)
+    let bytes = fs::read(path)?;
+    if bytes.starts_with(b"MZG1") {
+        return MazeProof::unpack(&bytes);
+    }
+
+    if let Ok(text) = std::str::from_utf8(&bytes) {
+        if let Ok(maze_proof) = serde_json::from_str(text) {
+            return Ok(maze_proof);
+        }
+    }
+
+    rmp_serde::from_slice(&bytes)
+        .map_err(|e| format!("'{}' is not a valid packed, JSON, or MessagePack maze proof: {}", path, e).into())
 }
 
-fn save_path_proof(path_proof: &PathProof, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let json = serde_json::to_string_pretty(path_proof)?;
-    fs::write(path, json)?;
+fn save_path_proof(path_proof: &PathProof, path: &str, format: ProofFormat) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        ProofFormat::Json => {
+            let json = serde_json::to_string_pretty(path_proof)?;
+            fs::write(path, json)?;
+        }
+        ProofFormat::Packed => {
+            fs::write(path, path_proof.pack()?)?;
+        }
+        ProofFormat::MsgPack => {
+            fs::write(path, rmp_serde::to_vec(path_proof)?)?;
+        }
+    }
     Ok(())
 }
 
+/// Load a path proof, auto-detecting whether `path` holds a
+/// `PathProof::pack` blob (by magic bytes), JSON, or MessagePack.
 fn load_path_proof(path: &str) -> Result<PathProof, Box<dyn std::error::Error>> {
-    let json = fs::read_to_string(path)?;
-    let path_proof: PathProof = serde_json::from_str(&json)?;
-    Ok(path_proof)
+    let bytes = fs::read(path)?;
+    if bytes.starts_with(b"MZP1") {
+        return PathProof::unpack(&bytes);
+    }
+
+    if let Ok(text) = std::str::from_utf8(&bytes) {
+        if let Ok(path_proof) = serde_json::from_str(text) {
+            return Ok(path_proof);
+        }
+    }
+
+    rmp_serde::from_slice(&bytes)
+        .map_err(|e| format!("'{}' is not a valid packed, JSON, or MessagePack path proof: {}", path, e).into())
 }
 
 fn load_moves(path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
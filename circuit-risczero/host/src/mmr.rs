@@ -0,0 +1,297 @@
+//! Merkle Mountain Range aggregation of verified path proofs.
+//!
+//! A tournament can append every solved maze's [`PathProof`] as a leaf,
+//! publish the single 32-byte [`MmrAccumulator::root`], and each player
+//! proves their own solution is part of that root with a small
+//! [`InclusionProof`] instead of shipping the full proof set.
+
+use crate::PathProof;
+use risc0_zkvm::sha::{Impl as SHA256, Sha256};
+use serde::{Deserialize, Serialize};
+
+/// A 32-byte node hash in the range
+pub type Hash = [u8; 32];
+
+fn hash_bytes(data: &[u8]) -> Hash {
+    let digest = SHA256::hash_bytes(data);
+    let mut out = [0u8; 32];
+    out.copy_from_slice(digest.as_bytes());
+    out
+}
+
+/// Hash two child nodes into their parent: `H(left || right)`
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    hash_bytes(&buf)
+}
+
+/// Leaf commitment for a path proof: `H(maze_seed || grid_hash || is_valid)`
+fn hash_leaf(maze_seed: u32, grid_hash: &[u8; 32], is_valid: bool) -> Hash {
+    let mut buf = [0u8; 4 + 32 + 1];
+    buf[..4].copy_from_slice(&maze_seed.to_le_bytes());
+    buf[4..36].copy_from_slice(grid_hash);
+    buf[36] = is_valid as u8;
+    hash_bytes(&buf)
+}
+
+/// One entry in the peak list: the root hash of a complete binary subtree
+/// of the given height (0 = a single leaf).
+#[derive(Debug, Clone, Copy)]
+struct Peak {
+    height: u32,
+    hash: Hash,
+}
+
+/// Fold a peak list (left to right) into a single root by bagging from the
+/// rightmost peak leftward: `acc = H(peak_i || acc)`.
+fn bag_peaks(peaks: &[Hash]) -> Hash {
+    let mut iter = peaks.iter().rev();
+    let mut acc = match iter.next() {
+        Some(&hash) => hash,
+        None => [0u8; 32],
+    };
+    for &peak in iter {
+        acc = hash_pair(&peak, &acc);
+    }
+    acc
+}
+
+/// An append-only Merkle Mountain Range over [`PathProof`] leaves.
+///
+/// Leaves are kept in append order and peaks are recomputed on demand
+/// rather than tracked incrementally, so building an [`InclusionProof`] is
+/// a single deterministic replay of the same append algorithm used for
+/// [`MmrAccumulator::root`] - simple to audit, at the cost of being O(n)
+/// per proof rather than O(log n).
+#[derive(Debug, Default)]
+pub struct MmrAccumulator {
+    leaves: Vec<Hash>,
+}
+
+impl MmrAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a verified path proof as the next leaf.
+    pub fn append(&mut self, proof: &PathProof) {
+        self.leaves
+            .push(hash_leaf(proof.maze_seed, &proof.grid_hash, proof.is_valid));
+    }
+
+    pub fn leaf_count(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Replay the MMR append rule over every leaf, returning the final
+    /// left-to-right peak list.
+    fn peaks(&self) -> Vec<Peak> {
+        let mut peaks: Vec<Peak> = Vec::new();
+        for &leaf in &self.leaves {
+            peaks.push(Peak { height: 0, hash: leaf });
+            while peaks.len() >= 2 {
+                let last = peaks[peaks.len() - 1];
+                let second_last = peaks[peaks.len() - 2];
+                if last.height != second_last.height {
+                    break;
+                }
+                peaks.pop();
+                peaks.pop();
+                peaks.push(Peak {
+                    height: last.height + 1,
+                    hash: hash_pair(&second_last.hash, &last.hash),
+                });
+            }
+        }
+        peaks
+    }
+
+    /// The current MMR root (bagged peaks).
+    pub fn root(&self) -> Hash {
+        let peaks: Vec<Hash> = self.peaks().iter().map(|p| p.hash).collect();
+        bag_peaks(&peaks)
+    }
+
+    /// Build an inclusion proof for the leaf appended at `leaf_index`.
+    pub fn prove_inclusion(&self, leaf_index: usize) -> Result<InclusionProof, String> {
+        if leaf_index >= self.leaves.len() {
+            return Err(format!(
+                "leaf index {} out of range (0..{})",
+                leaf_index,
+                self.leaves.len()
+            ));
+        }
+
+        // Replay the append algorithm leaf by leaf, tracking which peak
+        // slot our target leaf currently occupies and recording the
+        // sibling hash consumed at each merge along the way.
+        let mut peaks: Vec<Peak> = Vec::new();
+        let mut track_pos: Option<usize> = None;
+        let mut siblings: Vec<(Hash, bool)> = Vec::new(); // (sibling hash, sibling is on the right)
+
+        for (i, &leaf) in self.leaves.iter().enumerate() {
+            peaks.push(Peak { height: 0, hash: leaf });
+            if i == leaf_index {
+                track_pos = Some(peaks.len() - 1);
+            }
+
+            while peaks.len() >= 2 {
+                let last = peaks[peaks.len() - 1];
+                let second_last = peaks[peaks.len() - 2];
+                if last.height != second_last.height {
+                    break;
+                }
+
+                let merged_index = peaks.len() - 2;
+                if let Some(pos) = track_pos {
+                    if pos == peaks.len() - 1 {
+                        // our subtree is the right child; sibling sits on the left
+                        siblings.push((second_last.hash, false));
+                        track_pos = Some(merged_index);
+                    } else if pos == peaks.len() - 2 {
+                        // our subtree is the left child; sibling sits on the right
+                        siblings.push((last.hash, true));
+                        track_pos = Some(merged_index);
+                    }
+                }
+
+                peaks.pop();
+                peaks.pop();
+                peaks.push(Peak {
+                    height: last.height + 1,
+                    hash: hash_pair(&second_last.hash, &last.hash),
+                });
+            }
+        }
+
+        let peak_index = track_pos.ok_or("internal error: leaf was never tracked to a peak")?;
+        let other_peaks: Vec<Hash> = peaks
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx != peak_index)
+            .map(|(_, p)| p.hash)
+            .collect();
+
+        Ok(InclusionProof {
+            leaf_index,
+            leaf_hash: self.leaves[leaf_index],
+            siblings,
+            peak_index,
+            other_peaks,
+        })
+    }
+}
+
+/// Proof that a single leaf is part of an [`MmrAccumulator`] root: the
+/// sibling hashes climbing from the leaf to its peak, plus the other peaks
+/// needed to redo the bagging fold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    /// Append order index of this leaf
+    pub leaf_index: usize,
+    /// The leaf's own commitment hash
+    pub leaf_hash: Hash,
+    /// Sibling hashes from the leaf up to its peak, each tagged with
+    /// whether the sibling is the right-hand operand of the hash
+    pub siblings: Vec<(Hash, bool)>,
+    /// Index of this leaf's peak among the accumulator's final peak list
+    pub peak_index: usize,
+    /// The other peaks (in left-to-right order, this peak's slot omitted)
+    pub other_peaks: Vec<Hash>,
+}
+
+/// Verify an [`InclusionProof`] against a published MMR `root`.
+pub fn verify_inclusion(root: Hash, proof: &InclusionProof) -> bool {
+    let mut acc = proof.leaf_hash;
+    for (sibling, sibling_on_right) in &proof.siblings {
+        acc = if *sibling_on_right {
+            hash_pair(&acc, sibling)
+        } else {
+            hash_pair(sibling, &acc)
+        };
+    }
+
+    let mut peaks = proof.other_peaks.clone();
+    if proof.peak_index > peaks.len() {
+        return false;
+    }
+    peaks.insert(proof.peak_index, acc);
+
+    bag_peaks(&peaks) == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds an accumulator directly from synthetic leaf hashes, bypassing
+    /// [`MmrAccumulator::append`] (which needs a real [`PathProof`] receipt)
+    /// so the peak-bagging and inclusion-proof logic can be exercised at
+    /// every leaf count without proving anything.
+    fn accumulator_with_leaves(count: usize) -> MmrAccumulator {
+        MmrAccumulator {
+            leaves: (0..count as u32).map(|i| hash_bytes(&i.to_le_bytes())).collect(),
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_round_trips_at_every_size_up_to_nine() {
+        for count in 1..=9 {
+            let mmr = accumulator_with_leaves(count);
+            let root = mmr.root();
+
+            for leaf_index in 0..count {
+                let proof = mmr.prove_inclusion(leaf_index).expect("leaf index is in range");
+                assert!(
+                    verify_inclusion(root, &proof),
+                    "leaf {leaf_index} of {count} should verify against the root"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_wrong_root() {
+        let mmr = accumulator_with_leaves(5);
+        let mut wrong_root = mmr.root();
+        wrong_root[0] ^= 0xff;
+
+        let proof = mmr.prove_inclusion(2).unwrap();
+        assert!(!verify_inclusion(wrong_root, &proof));
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_tampered_leaf_hash() {
+        let mmr = accumulator_with_leaves(5);
+        let root = mmr.root();
+
+        let mut proof = mmr.prove_inclusion(3).unwrap();
+        proof.leaf_hash[0] ^= 0xff;
+        assert!(!verify_inclusion(root, &proof));
+    }
+
+    #[test]
+    fn test_prove_inclusion_out_of_range_leaf_index_errs() {
+        let mmr = accumulator_with_leaves(3);
+        assert!(mmr.prove_inclusion(3).is_err());
+    }
+
+    #[test]
+    fn test_single_leaf_root_equals_leaf_hash() {
+        let mmr = accumulator_with_leaves(1);
+        assert_eq!(mmr.root(), mmr.leaves[0]);
+    }
+
+    #[test]
+    fn test_root_is_order_sensitive() {
+        let a = hash_bytes(b"a");
+        let b = hash_bytes(b"b");
+
+        let forward = MmrAccumulator { leaves: vec![a, b] };
+        let backward = MmrAccumulator { leaves: vec![b, a] };
+
+        assert_ne!(forward.root(), backward.root());
+    }
+}
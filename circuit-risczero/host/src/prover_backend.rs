@@ -0,0 +1,151 @@
+//! Pluggable backend for where zkVM proving actually runs (see
+//! [`ProverBackend`]), so `generate_maze_proof`/`verify_path_proof` aren't
+//! hard-wired to a local `default_prover()` - Groth16 compression in
+//! particular needs enough RAM/CPU that many callers want to offload it to a
+//! shared remote service rather than running it on their own machine.
+//!
+//! Mirrors the worker crate's `prover::Prover` trait in spirit (a pluggable
+//! backend selected by the caller, with a local and an HTTP-driven remote
+//! implementation), but speaks in `risc0_zkvm` types directly since this
+//! crate's proving happens in-process rather than over a subprocess/gRPC
+//! boundary.
+
+use risc0_zkvm::{default_prover, ExecutorEnv, ProverOpts, Receipt};
+
+use crate::ReceiptKind;
+
+/// Runs one `prove_with_opts`-shaped call against a guest ELF.
+///
+/// `env` is the already-built executor environment - used as-is by
+/// [`LocalProver`], exactly like `risc0_zkvm::default_prover()` would be.
+/// `witness_bytes` is the same input flattened to a single byte buffer, in
+/// write order, for backends that can't accept an in-process `ExecutorEnv`
+/// at all - a remote prover only ever sees bytes crossing the wire, the same
+/// way the worker crate's `Prover::prove` only ever sees a raw witness.
+/// `assumptions` are receipts already folded into `env` via
+/// `add_assumption` (proof composition, e.g. `verify_path_proof`'s maze
+/// receipt) - passed alongside separately since a remote backend needs them
+/// to resolve the same assumptions `env` can't be shipped to it to carry.
+pub trait ProverBackend: Send + Sync {
+    fn prove_with_opts(
+        &self,
+        env: ExecutorEnv<'_>,
+        witness_bytes: &[u8],
+        assumptions: &[Receipt],
+        elf: &[u8],
+        opts: &ProverOpts,
+        receipt_kind: ReceiptKind,
+    ) -> Result<Receipt, Box<dyn std::error::Error>>;
+}
+
+/// Proves on the local machine via `risc0_zkvm::default_prover()`. The
+/// default backend used wherever no `&dyn ProverBackend` is given.
+#[derive(Default)]
+pub struct LocalProver;
+
+impl ProverBackend for LocalProver {
+    fn prove_with_opts(
+        &self,
+        env: ExecutorEnv<'_>,
+        _witness_bytes: &[u8],
+        _assumptions: &[Receipt],
+        elf: &[u8],
+        opts: &ProverOpts,
+        _receipt_kind: ReceiptKind,
+    ) -> Result<Receipt, Box<dyn std::error::Error>> {
+        let prove_info = default_prover()
+            .prove_with_opts(env, elf, opts)
+            .map_err(|e| format!("Local proving failed: {}", e))?;
+        Ok(prove_info.receipt)
+    }
+}
+
+#[derive(serde::Serialize)]
+struct RemoteProveRequest<'a> {
+    elf: &'a [u8],
+    input: &'a [u8],
+    assumptions: &'a [Receipt],
+    receipt_kind: u8,
+}
+
+#[derive(serde::Deserialize)]
+struct RemoteProveResponse {
+    receipt: Receipt,
+}
+
+/// Proves against a Bonsai-style remote proving service: POSTs the guest
+/// ELF and flattened input bytes, and expects the receipt back in the
+/// response body. Blocking, the same way `bonsai_sdk`'s own client is
+/// blocking rather than async - callers that need this off the async
+/// executor should call [`crate::generate_maze_proof_with_prover`] from
+/// their own `spawn_blocking` (the async wrappers such as
+/// [`crate::generate_maze_proof_async`] always use [`LocalProver`]) instead
+/// of making this trait itself async.
+pub struct RemoteProver {
+    endpoint: String,
+    api_key: String,
+    client: reqwest::blocking::Client,
+}
+
+impl RemoteProver {
+    pub fn new(endpoint: impl Into<String>, api_key: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            api_key: api_key.into(),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+}
+
+impl ProverBackend for RemoteProver {
+    fn prove_with_opts(
+        &self,
+        _env: ExecutorEnv<'_>,
+        witness_bytes: &[u8],
+        assumptions: &[Receipt],
+        elf: &[u8],
+        _opts: &ProverOpts,
+        receipt_kind: ReceiptKind,
+    ) -> Result<Receipt, Box<dyn std::error::Error>> {
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .json(&RemoteProveRequest {
+                elf,
+                input: witness_bytes,
+                assumptions,
+                receipt_kind: receipt_kind.as_tag(),
+            })
+            .send()
+            .map_err(|e| format!("request to {} failed: {}", self.endpoint, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "remote prover at {} returned {}",
+                self.endpoint,
+                response.status()
+            )
+            .into());
+        }
+
+        let parsed: RemoteProveResponse = response
+            .json()
+            .map_err(|e| format!("failed to decode response from {}: {}", self.endpoint, e))?;
+
+        Ok(parsed.receipt)
+    }
+}
+
+/// Serialize `value` the way `ExecutorEnvBuilder::write` does (risc0's
+/// word-aligned serde codec), flattened to little-endian bytes, so a
+/// [`RemoteProver`] can see the same bytes a local `ExecutorEnv` would
+/// have been built from.
+pub(crate) fn word_bytes<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let words = risc0_zkvm::serde::to_vec(value).map_err(|e| format!("failed to serialize witness field: {}", e))?;
+    let mut bytes = Vec::with_capacity(words.len() * 4);
+    for word in words {
+        bytes.extend_from_slice(&word.to_le_bytes());
+    }
+    Ok(bytes)
+}
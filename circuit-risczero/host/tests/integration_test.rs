@@ -1,22 +1,30 @@
-use host::{generate_maze_proof, verify_path_proof, ReceiptKind};
+use host::{
+    generate_maze_proof, verify_path_proof, verify_path_proof_merkle, BatchValidator, MazeProof,
+    MazeProofBundle, PathProof, PathProofBundle, ReceiptKind,
+};
+use maze_core::{Maze, RngBackend, MAZE_COLS, MAZE_ROWS};
+use methods::{MAZE_GEN_ID, PATH_VERIFY_ID};
 
 /// The known maze seed for testing
 const MAZE_SEED: u32 = 2918957128;
 
-/// Full 312-move BFS solution for the test maze
+/// Full 156-cell BFS solution for the test maze, from the seed's randomized
+/// start cell (6, 2) to its randomized goal cell (3, 13). Each cell-to-cell
+/// step is two grid moves (through the carved wall, then into the next cell
+/// center), so every direction below appears twice in a row.
 /// Directions: 0=NORTH, 1=EAST, 2=SOUTH, 3=WEST
 const TEST_MOVES: &[u8] = &[
-    1, 1, 2, 2, 3, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 2, 2, 3, 3, 2, 2, 1, 1,
-    1, 1, 2, 2, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1, 0, 0, 1, 1, 0, 0, 3, 3, 0, 0, 0, 0, 3, 3, 3, 3,
-    0, 0, 3, 3, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 2, 2, 2, 2, 2, 2,
-    3, 3, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0,
-    0, 0, 1, 1, 2, 2, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 2, 2,
-    2, 2, 1, 1, 2, 2, 3, 3, 2, 2, 1, 1, 2, 2, 2, 2, 2, 2, 3, 3, 2, 2, 3, 3, 2, 2, 3, 3, 3, 3,
-    2, 2, 1, 1, 2, 2, 3, 3, 2, 2, 3, 3, 2, 2, 2, 2, 2, 2, 3, 3, 0, 0, 3, 3, 0, 0, 1, 1, 0, 0,
-    3, 3, 3, 3, 2, 2, 3, 3, 3, 3, 2, 2, 1, 1, 1, 1, 2, 2, 3, 3, 2, 2, 1, 1, 1, 1, 1, 1, 2, 2,
-    1, 1, 2, 2, 3, 3, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 3, 3,
-    3, 3, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 3, 3, 2, 2, 1, 1,
+    3, 3, 2, 2, 1, 1, 2, 2, 2, 2, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3,
+    0, 0, 3, 3, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 2, 2, 1, 1, 2, 2, 1, 1, 1, 1, 0, 0,
+    1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 2, 2,
+    1, 1, 2, 2, 3, 3, 3, 3, 0, 0, 3, 3, 2, 2, 2, 2, 1, 1, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1,
+    2, 2, 1, 1, 2, 2, 3, 3, 2, 2, 1, 1, 2, 2, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 3, 3, 0, 0,
+    1, 1, 0, 0, 1, 1, 0, 0, 3, 3, 3, 3, 0, 0, 1, 1, 0, 0, 0, 0, 1, 1, 2, 2, 2, 2, 1, 1, 0, 0,
+    0, 0, 1, 1, 1, 1, 2, 2, 3, 3, 2, 2, 2, 2, 3, 3, 2, 2, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 2, 2,
+    1, 1, 2, 2, 2, 2, 3, 3, 2, 2, 3, 3, 2, 2, 1, 1, 2, 2, 1, 1, 1, 1, 0, 0, 1, 1, 2, 2, 1, 1,
+    0, 0, 0, 0, 3, 3, 3, 3, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 3, 3, 0, 0, 1, 1, 0, 0,
+    3, 3, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 3, 3, 2, 2, 3, 3, 0, 0, 3, 3, 0, 0, 0, 0,
+    3, 3, 2, 2, 2, 2, 3, 3, 0, 0, 0, 0,
 ];
 
 #[test]
@@ -118,3 +126,275 @@ fn test_invalid_moves() {
 
     println!("✅ Invalid moves test passed!");
 }
+
+/// The canonical full-size (`MAZE_ROWS` x `MAZE_COLS`, Minstd, unbraided)
+/// maze's Merkle root for `MAZE_SEED`, computed the same way
+/// `verify_path_proof_merkle` regenerates and checks it - the advertised
+/// 20x20 use case, which previously panicked on an out-of-bounds array
+/// write the first time a 1681-leaf grid's root was folded.
+fn canonical_maze_root() -> [u8; 32] {
+    let (maze, _) = Maze::generate_with_rng(MAZE_ROWS, MAZE_COLS, MAZE_SEED, RngBackend::Minstd, 0);
+    maze.merkle_grid().merkle_root()
+}
+
+#[test]
+fn test_merkle_path_valid_bfs_solution_at_full_grid_size() {
+    println!("🧪 Testing Merkle-backed path verification at full 20x20 grid size...");
+
+    let maze_root = canonical_maze_root();
+    let moves = TEST_MOVES.to_vec();
+    let result = verify_path_proof_merkle(maze_root, MAZE_SEED, moves, ReceiptKind::Composite)
+        .expect("Merkle path verification failed");
+
+    assert!(
+        result.is_valid,
+        "BFS solution should be valid but got invalid"
+    );
+    assert_eq!(result.maze_seed, MAZE_SEED, "Maze seed should match input");
+    assert_eq!(result.maze_root, maze_root, "Maze root should match input");
+
+    println!("✅ Merkle-backed full grid size test passed!");
+}
+
+#[test]
+fn test_merkle_path_rejects_mismatched_root() {
+    println!("🧪 Testing Merkle-backed path verification with a wrong root...");
+
+    let mut wrong_root = canonical_maze_root();
+    wrong_root[0] ^= 0xff;
+
+    let moves = TEST_MOVES.to_vec();
+    let result = verify_path_proof_merkle(wrong_root, MAZE_SEED, moves, ReceiptKind::Composite);
+
+    assert!(
+        result.is_err(),
+        "Mismatched maze_root should be rejected before proving"
+    );
+
+    println!("✅ Mismatched root rejection test passed!");
+}
+
+/// A mixed batch of a valid maze proof, a valid path proof, and a path proof
+/// whose receipt actually belongs to the maze-gen guest (so it fails the
+/// image ID pin before any crypto runs) - exercises `BatchValidator`'s
+/// group-by-`(image_id, ReceiptKind)` partitioning and `BatchError`'s
+/// failure-index sorting across queue positions.
+#[test]
+fn test_batch_validator_mixed_batch_reports_correct_failing_index() {
+    println!("🧪 Testing BatchValidator with a mixed batch...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+    let path_proof = verify_path_proof(&maze_proof, TEST_MOVES.to_vec(), None).expect("Path verification failed");
+
+    // A "path proof" carrying a maze-gen receipt instead of a path-verify
+    // one - cryptographically well-formed, but the wrong guest, so
+    // `verify_path_proof_receipt`'s image ID pin must reject it.
+    let bogus_path_proof = PathProof {
+        is_valid: path_proof.is_valid,
+        maze_seed: path_proof.maze_seed,
+        grid_hash: path_proof.grid_hash,
+        identity_root: None,
+        nullifier_hash: None,
+        receipt: maze_proof.receipt.clone(),
+        receipt_kind: ReceiptKind::Composite,
+    };
+
+    let mut batch = BatchValidator::new();
+    batch.queue_maze(&maze_proof); // index 0: valid
+    batch.queue(&bogus_path_proof); // index 1: wrong guest, must fail
+    batch.queue(&path_proof); // index 2: valid
+
+    let err = batch.validate().expect_err("batch with a bad receipt should fail");
+    assert_eq!(
+        err.failures.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![1],
+        "only the bogus path proof at index 1 should be reported as failing"
+    );
+
+    println!("✅ BatchValidator mixed batch test passed!");
+}
+
+/// Same shape as [`test_batch_validator_mixed_batch_reports_correct_failing_index`],
+/// but with every receipt generated at `Succinct` kind, so the passing
+/// entries land in `BatchValidator::validate`'s parallel (`std::thread::scope`)
+/// path rather than the `Composite` sequential one. Succinct proving is
+/// expensive, so this is `#[ignore]`d by default - run explicitly with
+/// `cargo test -- --ignored` to exercise the parallel group path.
+#[test]
+#[ignore]
+fn test_batch_validator_mixed_batch_succinct_parallel_path() {
+    println!("🧪 Testing BatchValidator with a Succinct mixed batch...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Succinct).expect("Maze proof generation failed");
+    let path_proof = verify_path_proof(&maze_proof, TEST_MOVES.to_vec(), None).expect("Path verification failed");
+
+    let bogus_path_proof = PathProof {
+        is_valid: path_proof.is_valid,
+        maze_seed: path_proof.maze_seed,
+        grid_hash: path_proof.grid_hash,
+        identity_root: None,
+        nullifier_hash: None,
+        receipt: maze_proof.receipt.clone(),
+        receipt_kind: ReceiptKind::Succinct,
+    };
+
+    let mut batch = BatchValidator::new();
+    batch.queue_maze(&maze_proof); // index 0: valid, own image ID group
+    batch.queue(&bogus_path_proof); // index 1: wrong guest, must fail
+    batch.queue(&path_proof); // index 2: valid
+
+    let err = batch.validate().expect_err("batch with a bad receipt should fail");
+    assert_eq!(
+        err.failures.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![1],
+        "only the bogus path proof at index 1 should be reported as failing"
+    );
+
+    println!("✅ BatchValidator Succinct parallel path test passed!");
+}
+
+/// `MazeProof::pack`/`unpack` hand-roll their own length-prefixed binary
+/// format, including flattening `grid_data` to a byte string on pack and
+/// re-chunking it by `cols * 2 + 1` on unpack - this round-trips every field
+/// (not just the receipt) to prove that chunking is really the inverse of
+/// the flatten, not just a plausible-looking one.
+#[test]
+fn test_maze_proof_pack_unpack_round_trip() {
+    println!("🧪 Testing MazeProof pack/unpack round trip...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+
+    let packed = maze_proof.pack().expect("packing should succeed");
+    let unpacked = MazeProof::unpack(&packed).expect("unpacking should succeed");
+
+    assert_eq!(unpacked.maze_seed, maze_proof.maze_seed);
+    assert_eq!(unpacked.grid_hash, maze_proof.grid_hash);
+    assert_eq!(unpacked.rng_backend, maze_proof.rng_backend);
+    assert_eq!(unpacked.braid_factor, maze_proof.braid_factor);
+    assert_eq!(unpacked.start_row, maze_proof.start_row);
+    assert_eq!(unpacked.start_col, maze_proof.start_col);
+    assert_eq!(unpacked.goal_row, maze_proof.goal_row);
+    assert_eq!(unpacked.goal_col, maze_proof.goal_col);
+    assert_eq!(unpacked.corridor_bias, maze_proof.corridor_bias);
+    assert_eq!(unpacked.rows, maze_proof.rows);
+    assert_eq!(unpacked.cols, maze_proof.cols);
+    assert_eq!(unpacked.receipt_kind, maze_proof.receipt_kind);
+    assert_eq!(
+        unpacked.grid_data, maze_proof.grid_data,
+        "grid_data should survive the flatten-on-pack / chunk-on-unpack round trip"
+    );
+
+    println!("✅ MazeProof pack/unpack round trip test passed!");
+}
+
+#[test]
+fn test_maze_proof_unpack_rejects_truncated_data() {
+    println!("🧪 Testing MazeProof::unpack with truncated data...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+    let packed = maze_proof.pack().expect("packing should succeed");
+
+    let result = MazeProof::unpack(&packed[..packed.len() - 10]);
+    assert!(result.is_err(), "truncated packed data should fail to unpack");
+
+    println!("✅ MazeProof truncated unpack test passed!");
+}
+
+/// Same round trip as [`test_maze_proof_pack_unpack_round_trip`], but for
+/// [`PathProof::pack`]/[`PathProof::unpack`] - which, unlike `MazeProof`'s,
+/// only accepts `ReceiptKind::Groth16` (the only kind with a seal compact
+/// enough to be worth packing). Groth16 proving is expensive, so this is
+/// `#[ignore]`d by default - run explicitly with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn test_path_proof_pack_unpack_round_trip() {
+    println!("🧪 Testing PathProof pack/unpack round trip...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Groth16).expect("Maze proof generation failed");
+    let path_proof = verify_path_proof(&maze_proof, TEST_MOVES.to_vec(), Some(ReceiptKind::Groth16))
+        .expect("Path verification failed");
+
+    let packed = path_proof.pack().expect("packing a Groth16 proof should succeed");
+    let unpacked = PathProof::unpack(&packed).expect("unpacking should succeed");
+
+    assert_eq!(unpacked.is_valid, path_proof.is_valid);
+    assert_eq!(unpacked.maze_seed, path_proof.maze_seed);
+    assert_eq!(unpacked.grid_hash, path_proof.grid_hash);
+    assert_eq!(unpacked.identity_root, path_proof.identity_root);
+    assert_eq!(unpacked.nullifier_hash, path_proof.nullifier_hash);
+    assert_eq!(unpacked.receipt_kind, path_proof.receipt_kind);
+
+    println!("✅ PathProof pack/unpack round trip test passed!");
+}
+
+/// A path under the system temp directory, unique to this process and test
+/// name, for [`MazeProofBundle::save_bundle`]/[`PathProofBundle::save_bundle`]
+/// round-trip tests that need a real file on disk.
+fn scratch_bundle_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("zkp_maze_bundle_test_{}_{}.bundle", std::process::id(), name))
+}
+
+#[test]
+fn test_maze_proof_bundle_save_load_verify_round_trip() {
+    println!("🧪 Testing MazeProofBundle save/load/verify round trip...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+    let bundle = MazeProofBundle::from_maze_proof(&maze_proof).expect("bundling should succeed");
+
+    let path = scratch_bundle_path("maze");
+    bundle.save_bundle(&path).expect("saving should succeed");
+    let loaded = MazeProofBundle::load_bundle(&path).expect("loading should succeed");
+    loaded.verify_bundle(MAZE_GEN_ID).expect("bundle should verify against MAZE_GEN_ID");
+
+    let recovered = loaded.into_maze_proof().expect("reconstructing should succeed");
+    assert_eq!(recovered.maze_seed, maze_proof.maze_seed);
+    assert_eq!(recovered.grid_hash, maze_proof.grid_hash);
+    assert_eq!(recovered.grid_data, maze_proof.grid_data);
+
+    std::fs::remove_file(&path).ok();
+    println!("✅ MazeProofBundle round trip test passed!");
+}
+
+#[test]
+fn test_maze_proof_bundle_rejects_tampered_payload() {
+    println!("🧪 Testing MazeProofBundle CRC32 tamper detection...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+    let bundle = MazeProofBundle::from_maze_proof(&maze_proof).expect("bundling should succeed");
+
+    let path = scratch_bundle_path("maze_tamper");
+    bundle.save_bundle(&path).expect("saving should succeed");
+
+    let mut bytes = std::fs::read(&path).expect("reading back should succeed");
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    std::fs::write(&path, &bytes).expect("rewriting should succeed");
+
+    let result = MazeProofBundle::load_bundle(&path);
+    assert!(result.is_err(), "a tampered bundle should fail its CRC32 check");
+
+    std::fs::remove_file(&path).ok();
+    println!("✅ MazeProofBundle tamper detection test passed!");
+}
+
+#[test]
+fn test_path_proof_bundle_save_load_verify_round_trip() {
+    println!("🧪 Testing PathProofBundle save/load/verify round trip...");
+
+    let maze_proof = generate_maze_proof(MAZE_SEED, ReceiptKind::Composite).expect("Maze proof generation failed");
+    let path_proof = verify_path_proof(&maze_proof, TEST_MOVES.to_vec(), None).expect("Path verification failed");
+    let bundle = PathProofBundle::from_path_proof(&path_proof).expect("bundling should succeed");
+
+    let path = scratch_bundle_path("path");
+    bundle.save_bundle(&path).expect("saving should succeed");
+    let loaded = PathProofBundle::load_bundle(&path).expect("loading should succeed");
+    loaded.verify_bundle(PATH_VERIFY_ID).expect("bundle should verify against PATH_VERIFY_ID");
+
+    let recovered = loaded.into_path_proof().expect("reconstructing should succeed");
+    assert_eq!(recovered.is_valid, path_proof.is_valid);
+    assert_eq!(recovered.maze_seed, path_proof.maze_seed);
+    assert_eq!(recovered.grid_hash, path_proof.grid_hash);
+
+    std::fs::remove_file(&path).ok();
+    println!("✅ PathProofBundle round trip test passed!");
+}
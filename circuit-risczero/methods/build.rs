@@ -18,10 +18,12 @@ fn main() {
             .build()
             .unwrap();
 
-        // Apply to all guest packages (maze-gen and path-verify)
+        // Apply to all guest packages (maze-gen, path-verify, path-verify-merkle, and path-verify-journey)
         let methods_map = HashMap::from([
             ("maze-gen", guest_opts.clone()),
-            ("path-verify", guest_opts),
+            ("path-verify", guest_opts.clone()),
+            ("path-verify-merkle", guest_opts.clone()),
+            ("path-verify-journey", guest_opts),
         ]);
 
         risc0_build::embed_methods_with_options(methods_map);
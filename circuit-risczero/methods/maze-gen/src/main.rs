@@ -5,14 +5,14 @@ extern crate alloc;
 
 use risc0_zkvm::guest::env;
 use risc0_zkvm::sha::{Impl as SHA256, Sha256};
-use maze_core::{MazeJournal, Maze, MAZE_ROWS, MAZE_COLS};
+use maze_core::{MazeJournal, Maze, RngBackend};
 
 risc0_zkvm::guest::entry!(main);
 
 /// Maze Generation Guest Program (Hash-Based Architecture)
 ///
 /// This program generates a maze from a seed and commits a SHA-256 hash
-/// of the grid to the journal. This reduces journal size by 97.9% while
+/// of the grid to the journal. This reduces journal size by 97.3% while
 /// maintaining cryptographic integrity.
 ///
 /// SECURITY MODEL:
@@ -20,32 +20,54 @@ risc0_zkvm::guest::entry!(main);
 /// - zkVM receipt cryptographically guarantees maze authenticity
 /// - Grid hash serves as a commitment to the maze configuration
 /// - Anyone can verify the maze by regenerating and hashing the grid
+/// - Runtime rows/cols are committed rather than assumed fixed, so a
+///   verifier knows exactly what dimensions the hash covers
 ///
 /// PERFORMANCE:
-/// - Journal size: 36 bytes (vs 1,685 bytes previously)
-/// - SHA-256 cost: ~1,842 cycles (6 + 68×27 blocks)
-/// - Negligible overhead (~0.04% of total execution)
+/// - Journal size: 45 bytes (vs 1,685 bytes previously)
+/// - SHA-256 cost: proportional to rows*cols, negligible at guest-max size
+/// - Negligible overhead (~0.04% of total execution at 20x20)
 
 fn main() {
-    // Read maze seed from host
+    // Read maze seed, RNG backend selection, braid factor, growing-tree
+    // corridor bias, and runtime dimensions from host. Dimensions may be
+    // anything up to the guest's compiled-in MAZE_ROWS/MAZE_COLS maximum -
+    // `Maze::generate_with_rng_and_bias` asserts this.
     let maze_seed: u32 = env::read();
+    let rng_backend_id: u8 = env::read();
+    let rng_backend = RngBackend::from_u8(rng_backend_id);
+    let braid_factor: u8 = env::read();
+    let corridor_bias: u8 = env::read();
+    let rows: u8 = env::read();
+    let cols: u8 = env::read();
+
+    // Generate maze from seed (deterministic), braiding in loops if requested
+    let (maze, mut rng) = Maze::generate_with_rng_and_bias(
+        rows as usize, cols as usize, maze_seed, rng_backend, braid_factor, corridor_bias,
+    );
+
+    // Continue the same RNG stream to pick a randomized entrance and exit,
+    // rather than assuming fixed corners
+    let (start_row, start_col, goal_row, goal_col) = maze.select_start_and_goal(&mut rng);
 
-    // Generate maze from seed (deterministic)
-    let maze = Maze::generate(MAZE_ROWS, MAZE_COLS, maze_seed);
     let grid = maze.to_binary_grid();
 
-    // Flatten the grid into a byte array for hashing
-    let mut grid_bytes = [0u8; (MAZE_ROWS * 2 + 1) * (MAZE_ROWS * 2 + 1)];
-    let grid_size = MAZE_ROWS * 2 + 1;
-    for i in 0..grid_size {
-        for j in 0..grid_size {
-            grid_bytes[i * grid_size + j] = grid[i][j];
+    // Flatten only the runtime-sized region of the grid into a byte slice
+    // for hashing - the fixed array beyond (grid_rows) x (grid_cols) is
+    // scratch space from the guest's compiled-in maximum and isn't part of
+    // this maze's commitment.
+    let grid_rows = rows as usize * 2 + 1;
+    let grid_cols = cols as usize * 2 + 1;
+    let mut grid_bytes = [0u8; maze_core::GRID_DATA_SIZE];
+    for i in 0..grid_rows {
+        for j in 0..grid_cols {
+            grid_bytes[i * grid_cols + j] = grid[i][j];
         }
     }
+    let grid_slice = &grid_bytes[..grid_rows * grid_cols];
 
     // Hash the grid using RISC Zero's accelerated SHA-256
-    // Cost: 6 + 68 × ceil(1681/64) = 6 + 68 × 27 = 1,842 cycles
-    let hash_digest = SHA256::hash_bytes(&grid_bytes);
+    let hash_digest = SHA256::hash_bytes(grid_slice);
     let hash_bytes = hash_digest.as_bytes();
 
     // Convert to fixed-size array
@@ -56,11 +78,33 @@ fn main() {
     let journal = MazeJournal {
         maze_seed,
         grid_hash,
+        rng_backend: rng_backend_id,
+        braid_factor,
+        start_row: start_row as u8,
+        start_col: start_col as u8,
+        goal_row: goal_row as u8,
+        goal_col: goal_col as u8,
+        corridor_bias,
+        rows,
+        cols,
     };
 
     // Commit to journal
-    // Format: maze_seed (u32, 4 bytes) + grid_hash (32 bytes) = 36 bytes
-    // This is 97.9% smaller than the previous 1,685 byte journal!
+    // Format: maze_seed (u32, 4 bytes) + grid_hash (32 bytes) + rng_backend (1 byte)
+    // + braid_factor (1 byte) + start/goal cell coords (4 bytes) + corridor_bias (1 byte)
+    // + rows/cols (2 bytes) = 45 bytes
     env::commit(&journal.maze_seed);
     env::commit_slice(&journal.grid_hash);
+    // One byte each: env::commit would pad every u8 out to a 4-byte word.
+    env::commit_slice(&[
+        journal.rng_backend,
+        journal.braid_factor,
+        journal.start_row,
+        journal.start_col,
+        journal.goal_row,
+        journal.goal_col,
+        journal.corridor_bias,
+        journal.rows,
+        journal.cols,
+    ]);
 }
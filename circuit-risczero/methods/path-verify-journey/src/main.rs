@@ -0,0 +1,212 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::sha::{Digest, Impl as SHA256, Sha256};
+use maze_core::{MAX_MOVES, MAX_JOURNEY_MAZES, GRID_DATA_SIZE, MAZE_JOURNAL_SIZE};
+
+risc0_zkvm::guest::entry!(main);
+
+// Row/col deltas, matching `path-verify`'s lookup tables.
+// [NORTH, EAST, SOUTH, WEST]
+const ROW_DELTAS: [i32; 4] = [-1, 0, 1, 0];
+const COL_DELTAS: [i32; 4] = [0, 1, 0, -1];
+
+/// Multi-maze "journey" verification guest program
+///
+/// Proves a player solved an ordered sequence of up to `MAX_JOURNEY_MAZES`
+/// mazes back-to-back: each maze's generation receipt is folded in as its
+/// own `env::verify` assumption (the same composition `path-verify` uses for
+/// a single maze), and every segment's move validity is checked the same
+/// way `path-verify` checks its one segment. A game that wants to prove
+/// completion of a whole level set gets one succinct/groth16 receipt instead
+/// of one `PathJournal` per maze.
+///
+/// JOURNAL LAYOUT (variable length, decoded by the host):
+/// `count (u32) + seed_0..seed_{count-1} (u32 each) + all_valid (u32)`
+///
+/// SECURITY MODEL:
+/// - Every maze in the sequence is fully read and its moves validated
+///   (mirroring `path-verify`'s grid hash commitment, start/goal bounds, and
+///   sequential movement checks) regardless of whether an earlier segment
+///   already failed, so the journal always reports every seed in the
+///   sequence rather than stopping short.
+/// - A single invalid segment (bad grid hash, invalid moves, goal not
+///   reached) fails `all_valid` for the whole journey.
+fn main() {
+    let count: u32 = env::read();
+
+    if count as usize > MAX_JOURNEY_MAZES {
+        // The host claims more mazes than this guest is compiled to chain -
+        // there's no well-formed input length to expect, so reject without
+        // reading anything further.
+        commit_journey(&[], false);
+        return;
+    }
+
+    let mut seeds = [0u32; MAX_JOURNEY_MAZES];
+    let mut all_valid = true;
+
+    for seed_slot in seeds.iter_mut().take(count as usize) {
+        let segment_valid = verify_maze_segment(seed_slot);
+        all_valid = all_valid && segment_valid;
+    }
+
+    commit_journey(&seeds[..count as usize], all_valid);
+}
+
+/// Verify one maze segment of the journey: its maze-gen assumption, its
+/// grid against the committed hash, and the player's moves through it.
+/// Always writes the maze's seed into `seed_slot` and fully consumes this
+/// segment's input, regardless of validity, so the stream stays in sync for
+/// whichever segment comes next.
+fn verify_maze_segment(seed_slot: &mut u32) -> bool {
+    let mut maze_image_id_bytes = [0u8; 32];
+    env::read_slice(&mut maze_image_id_bytes);
+    let maze_image_id = Digest::try_from(maze_image_id_bytes.as_slice())
+        .expect("Invalid image ID format");
+
+    let mut maze_journal_bytes = [0u8; MAZE_JOURNAL_SIZE];
+    env::read_slice(&mut maze_journal_bytes);
+
+    let maze_seed = u32::from_le_bytes([
+        maze_journal_bytes[0],
+        maze_journal_bytes[1],
+        maze_journal_bytes[2],
+        maze_journal_bytes[3],
+    ]);
+    *seed_slot = maze_seed;
+
+    let mut committed_hash = [0u8; 32];
+    committed_hash.copy_from_slice(&maze_journal_bytes[4..4 + 32]);
+
+    // rng_backend (offset 36), braid_factor (offset 37), and corridor_bias
+    // (offset 42) aren't needed here - they only affect how the host
+    // regenerated the grid we're about to verify.
+    let start_row = maze_journal_bytes[38] as usize;
+    let start_col = maze_journal_bytes[39] as usize;
+    let goal_row = maze_journal_bytes[40] as usize;
+    let goal_col = maze_journal_bytes[41] as usize;
+    let rows = maze_journal_bytes[43] as usize;
+    let cols = maze_journal_bytes[44] as usize;
+
+    // Verify this segment's maze receipt. Adds an assumption to the
+    // journey's proof that will be resolved alongside every other segment's
+    // when a succinct or groth16 receipt is requested.
+    env::verify(maze_image_id, &maze_journal_bytes)
+        .expect("Failed to verify maze receipt");
+
+    let grid_rows = rows * 2 + 1;
+    let grid_cols = cols * 2 + 1;
+    let grid_len = grid_rows * grid_cols;
+
+    let mut grid_data = [0u8; GRID_DATA_SIZE];
+    env::read_slice(&mut grid_data[..grid_len]);
+
+    let hash_digest = SHA256::hash_bytes(&grid_data[..grid_len]);
+    let mut computed_hash = [0u8; 32];
+    computed_hash.copy_from_slice(hash_digest.as_bytes());
+
+    let mut grid = [[0u8; 41]; 41];
+    for i in 0..grid_rows {
+        for j in 0..grid_cols {
+            grid[i][j] = grid_data[i * grid_cols + j];
+        }
+    }
+
+    let start = (start_row * 2 + 1, start_col * 2 + 1);
+    let goal = (goal_row * 2 + 1, goal_col * 2 + 1);
+
+    // Read however many moves the host sent for this segment into an
+    // alloc'd buffer sized to the declared count, rather than a fixed
+    // `MAX_MOVES` array - `move_count` is only checked against `MAX_MOVES`
+    // after the read, so an over-long segment is rejected without losing
+    // sync with the mazes that follow it in the stream.
+    let move_count: u16 = env::read();
+    let mut moves = vec![0u8; move_count as usize];
+    if move_count > 0 {
+        env::read_slice(&mut moves);
+    }
+
+    if computed_hash != committed_hash {
+        return false;
+    }
+
+    if start.0 >= grid_rows || start.1 >= grid_cols || goal.0 >= grid_rows || goal.1 >= grid_cols
+        || grid[start.0][start.1] != 1 || grid[goal.0][goal.1] != 1
+    {
+        return false;
+    }
+
+    if move_count as usize > MAX_MOVES {
+        return false;
+    }
+
+    verify_maze_solution(&moves, &grid, grid_rows, grid_cols, start, goal)
+}
+
+/// Commit the journey's journal: the number of mazes, each one's seed in
+/// sequence order, then the overall validity flag - mirrors `path-verify`'s
+/// `commit_result` in spirit, but variable-length since a journey's maze
+/// count isn't fixed ahead of time.
+fn commit_journey(seeds: &[u32], all_valid: bool) {
+    let count = seeds.len() as u32;
+    env::commit(&count);
+    for seed in seeds {
+        env::commit(seed);
+    }
+    env::commit(&(all_valid as u32));
+}
+
+/// Walk `moves` from `start`, the same sequential/bounds/wall checks
+/// `path-verify`'s `verify_maze_solution` runs for its one segment.
+fn verify_maze_solution(
+    moves: &[u8],
+    grid: &[[u8; 41]; 41],
+    grid_rows: usize,
+    grid_cols: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> bool {
+    let mut row = start.0;
+    let mut col = start.1;
+    let mut has_reached_end = false;
+
+    if grid[row][col] != 1 {
+        return false;
+    }
+
+    for &direction in moves {
+        if has_reached_end {
+            break;
+        }
+
+        if direction > 3 {
+            return false;
+        }
+
+        let dir_idx = direction as usize;
+        let next_row = (row as i32).wrapping_add(ROW_DELTAS[dir_idx]) as usize;
+        let next_col = (col as i32).wrapping_add(COL_DELTAS[dir_idx]) as usize;
+
+        if next_row >= grid_rows || next_col >= grid_cols {
+            return false;
+        }
+
+        if grid[next_row][next_col] != 1 {
+            return false;
+        }
+
+        row = next_row;
+        col = next_col;
+
+        if (row, col) == goal {
+            has_reached_end = true;
+        }
+    }
+
+    has_reached_end
+}
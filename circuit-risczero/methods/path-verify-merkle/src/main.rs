@@ -0,0 +1,157 @@
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use risc0_zkvm::guest::env;
+use maze_core::{merkle_grid::{verify_inclusion, InclusionProof, MERKLE_GRID_DEPTH}, MAX_MOVES, PathMerkleJournal};
+
+risc0_zkvm::guest::entry!(main);
+
+// Row/col deltas, matching `path-verify`'s lookup tables.
+// [NORTH, EAST, SOUTH, WEST]
+const ROW_DELTAS: [i32; 4] = [-1, 0, 1, 0];
+const COL_DELTAS: [i32; 4] = [0, 1, 0, -1];
+
+/// Merkle-backed path verification guest program
+///
+/// Proves that a move sequence walks from a maze's start cell to its goal
+/// cell without ever being handed the full grid: each cell the path visits
+/// is opened against a committed [`maze_core::merkle_grid::MerkleGrid`] root
+/// via an inclusion proof instead of being read out of a shipped grid array.
+///
+/// Unlike `path-verify`, this program doesn't verify a maze-gen receipt -
+/// `maze_root`, `rows`/`cols`, and `start`/`goal` are taken as given and
+/// committed into the journal as the claim being proven, rather than as
+/// facts cross-checked against an existing maze-gen proof. Binding this
+/// root back to a specific maze-gen proof (so a verifier doesn't have to
+/// trust the host's choice of `maze_root` out of band) is a follow-up.
+fn main() {
+    let maze_seed: u32 = env::read();
+
+    let mut maze_root = [0u8; 32];
+    env::read_slice(&mut maze_root);
+
+    let rows: u8 = env::read();
+    let cols: u8 = env::read();
+    let start_row: u8 = env::read();
+    let start_col: u8 = env::read();
+    let goal_row: u8 = env::read();
+    let goal_col: u8 = env::read();
+
+    let grid_rows = rows as usize * 2 + 1;
+    let grid_cols = cols as usize * 2 + 1;
+    let start = (start_row as usize * 2 + 1, start_col as usize * 2 + 1);
+    let goal = (goal_row as usize * 2 + 1, goal_col as usize * 2 + 1);
+
+    let move_count: u16 = env::read();
+    if move_count > MAX_MOVES as u16 || start.0 >= grid_rows || start.1 >= grid_cols || goal.0 >= grid_rows || goal.1 >= grid_cols {
+        commit_result(false, maze_seed, maze_root);
+        return;
+    }
+
+    let mut moves = [0u8; MAX_MOVES];
+    let actual_move_count = move_count as usize;
+    if actual_move_count > 0 {
+        env::read_slice(&mut moves[..actual_move_count]);
+    }
+
+    // Number of cell openings the host supplied: the start cell plus one
+    // per move actually walked before either the path went invalid or the
+    // goal was reached, so a short valid path doesn't pay for openings past
+    // its own end.
+    let proof_count: u16 = env::read();
+    if proof_count == 0 || proof_count > move_count + 1 {
+        commit_result(false, maze_seed, maze_root);
+        return;
+    }
+
+    let is_valid = verify_path(
+        &moves[..actual_move_count],
+        proof_count as usize,
+        maze_root,
+        grid_rows,
+        grid_cols,
+        start,
+        goal,
+    );
+
+    commit_result(is_valid, maze_seed, maze_root);
+}
+
+fn read_opening() -> (u8, InclusionProof) {
+    let value: u8 = env::read();
+    let mut siblings = [[0u8; 32]; MERKLE_GRID_DEPTH];
+    for sibling in siblings.iter_mut() {
+        env::read_slice(sibling);
+    }
+    let path_bits: u16 = env::read();
+    (value, (siblings, path_bits))
+}
+
+fn verify_path(
+    moves: &[u8],
+    proof_count: usize,
+    maze_root: [u8; 32],
+    grid_rows: usize,
+    grid_cols: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> bool {
+    let (mut row, mut col) = start;
+    let (value, proof) = read_opening();
+    if !verify_inclusion(maze_root, row, col, grid_cols, value, &proof) || value != 1 {
+        return false;
+    }
+
+    let mut opened = 1usize;
+    let mut has_reached_end = (row, col) == goal;
+
+    for &direction in moves {
+        if has_reached_end {
+            break;
+        }
+
+        if direction > 3 {
+            return false;
+        }
+        if opened >= proof_count {
+            // Host ran out of openings before the goal was reached.
+            return false;
+        }
+
+        let dir_idx = direction as usize;
+        let next_row = (row as i32).wrapping_add(ROW_DELTAS[dir_idx]) as usize;
+        let next_col = (col as i32).wrapping_add(COL_DELTAS[dir_idx]) as usize;
+
+        if next_row >= grid_rows || next_col >= grid_cols {
+            return false;
+        }
+
+        let (value, proof) = read_opening();
+        opened += 1;
+        if !verify_inclusion(maze_root, next_row, next_col, grid_cols, value, &proof) || value != 1 {
+            return false;
+        }
+
+        row = next_row;
+        col = next_col;
+        if (row, col) == goal {
+            has_reached_end = true;
+        }
+    }
+
+    has_reached_end
+}
+
+fn commit_result(is_valid: bool, maze_seed: u32, maze_root: [u8; 32]) {
+    let output = PathMerkleJournal {
+        is_valid: if is_valid { 1 } else { 0 },
+        maze_seed,
+        maze_root,
+    };
+
+    env::commit(&output.is_valid);
+    env::commit(&output.maze_seed);
+    env::commit_slice(&output.maze_root);
+}
@@ -5,7 +5,7 @@ extern crate alloc;
 
 use risc0_zkvm::guest::env;
 use risc0_zkvm::sha::{Digest, Impl as SHA256, Sha256};
-use maze_core::{PathJournal, MAX_MOVES, GRID_SIZE, GRID_DATA_SIZE, MAZE_JOURNAL_SIZE};
+use maze_core::{identity_tree, PathJournal, MAX_MOVES, GRID_DATA_SIZE, MAZE_JOURNAL_SIZE, IDENTITY_TREE_DEPTH};
 
 risc0_zkvm::guest::entry!(main);
 
@@ -29,12 +29,12 @@ const COL_DELTAS: [i32; 4] = [0, 1, 0, -1];
 /// - Grid is provided as untrusted input and verified via hash comparison
 /// - This proves the grid matches the verified maze without embedding it in the proof
 /// - Path verification ensures:
-///   * Fixed start position (1, 1)
+///   * Start/goal positions are read from the committed journal, not assumed corners
 ///   * Sequential movement validation (only +/-1 per move)
 ///   * Valid move directions (0-3 only)
-///   * Bounds checking (all positions < GRID_SIZE)
+///   * Bounds checking (all positions within the committed rows/cols)
 ///   * Wall collision detection (all positions are path cells)
-///   * Goal achievement (must reach end_pos, end_pos)
+///   * Goal achievement (must reach the committed goal cell)
 ///
 /// PROOF COMPOSITION:
 /// - The maze receipt is verified using env::verify()
@@ -43,8 +43,8 @@ const COL_DELTAS: [i32; 4] = [0, 1, 0, -1];
 /// - The final receipt proves both maze generation AND path validity
 ///
 /// PERFORMANCE:
-/// - SHA-256 verification cost: ~1,842 cycles (negligible)
-/// - Journal size reduction: 97.9% (1,685 bytes → 36 bytes)
+/// - SHA-256 verification cost: proportional to rows*cols, negligible at guest-max size
+/// - Journal size reduction: 97.3% (1,685 bytes → 45 bytes)
 
 fn main() {
     // Read the maze receipt image ID (32 bytes)
@@ -67,7 +67,17 @@ fn main() {
     ]);
 
     let mut committed_hash = [0u8; 32];
-    committed_hash.copy_from_slice(&maze_journal_bytes[4..MAZE_JOURNAL_SIZE]);
+    committed_hash.copy_from_slice(&maze_journal_bytes[4..4 + 32]);
+
+    // rng_backend (offset 36), braid_factor (offset 37), and corridor_bias
+    // (offset 42) aren't needed here - they only affect how the host
+    // regenerated the grid we're about to verify
+    let start_row = maze_journal_bytes[38] as usize;
+    let start_col = maze_journal_bytes[39] as usize;
+    let goal_row = maze_journal_bytes[40] as usize;
+    let goal_col = maze_journal_bytes[41] as usize;
+    let rows = maze_journal_bytes[43] as usize;
+    let cols = maze_journal_bytes[44] as usize;
 
     // Verify the maze receipt
     // This adds an assumption to our proof that will be resolved later
@@ -75,14 +85,21 @@ fn main() {
     env::verify(maze_image_id, &maze_journal_bytes)
         .expect("Failed to verify maze receipt");
 
+    // Grid dimensions are derived from the committed rows/cols, not assumed
+    // fixed. The buffer is still sized to the guest's compiled-in maximum;
+    // only the leading grid_rows x grid_cols region is read, hashed, and
+    // verified.
+    let grid_rows = rows * 2 + 1;
+    let grid_cols = cols * 2 + 1;
+    let grid_len = grid_rows * grid_cols;
+
     // Read the grid data as untrusted input from the host
     let mut grid_data = [0u8; GRID_DATA_SIZE];
-    env::read_slice(&mut grid_data);
+    env::read_slice(&mut grid_data[..grid_len]);
 
     // Hash the provided grid and verify it matches the committed hash
     // This proves the grid corresponds to the verified maze
-    // Cost: 6 + 68 × 27 = 1,842 cycles (negligible overhead)
-    let hash_digest = SHA256::hash_bytes(&grid_data);
+    let hash_digest = SHA256::hash_bytes(&grid_data[..grid_len]);
     let hash_bytes = hash_digest.as_bytes();
 
     let mut computed_hash = [0u8; 32];
@@ -91,34 +108,29 @@ fn main() {
     // SECURITY: Verify hash matches
     if computed_hash != committed_hash {
         // Grid doesn't match the verified maze - reject
-        let output = PathJournal {
-            is_valid: 0,
-            maze_seed,
-        };
-        env::commit(&output.is_valid);
-        env::commit(&output.maze_seed);
+        commit_result(false, maze_seed, [0u8; 32], [0u8; 32]);
         return;
     }
 
     // Convert flat grid data to 2D array for verification
     let mut grid = [[0u8; 41]; 41];
-    for i in 0..GRID_SIZE {
-        for j in 0..GRID_SIZE {
-            grid[i][j] = grid_data[i * GRID_SIZE + j];
+    for i in 0..grid_rows {
+        for j in 0..grid_cols {
+            grid[i][j] = grid_data[i * grid_cols + j];
         }
     }
 
-    // SECURITY: Validate start and end positions are paths
-    let start_pos = 1;
-    let end_pos = GRID_SIZE - 2;
-    if grid[start_pos][start_pos] != 1 || grid[end_pos][end_pos] != 1 {
-        // Start or end position is not a path - invalid maze
-        let output = PathJournal {
-            is_valid: 0,
-            maze_seed,
-        };
-        env::commit(&output.is_valid);
-        env::commit(&output.maze_seed);
+    // Convert committed cell coordinates to grid coordinates (cell centers
+    // live at row*2+1, col*2+1)
+    let start = (start_row * 2 + 1, start_col * 2 + 1);
+    let goal = (goal_row * 2 + 1, goal_col * 2 + 1);
+
+    // SECURITY: Validate start and goal positions are in bounds and are paths
+    if start.0 >= grid_rows || start.1 >= grid_cols || goal.0 >= grid_rows || goal.1 >= grid_cols
+        || grid[start.0][start.1] != 1 || grid[goal.0][goal.1] != 1
+    {
+        // Start or goal position is not a valid path cell - invalid maze
+        commit_result(false, maze_seed, [0u8; 32], [0u8; 32]);
         return;
     }
 
@@ -128,12 +140,7 @@ fn main() {
     // SECURITY: Reject if move count exceeds maximum
     // This prevents attempting to read more data than our buffer can hold
     if move_count > MAX_MOVES as u16 {
-        let output = PathJournal {
-            is_valid: 0,
-            maze_seed,
-        };
-        env::commit(&output.is_valid);
-        env::commit(&output.maze_seed);
+        commit_result(false, maze_seed, [0u8; 32], [0u8; 32]);
         return;
     }
 
@@ -144,10 +151,50 @@ fn main() {
     }
 
     // Verify the solution with the verified maze
-    // start_pos and end_pos already defined above (lines 79-80)
-    let is_valid = verify_maze_solution(&moves, &grid, GRID_SIZE, start_pos, end_pos);
+    let mut is_valid = verify_maze_solution(&moves, &grid, grid_rows, grid_cols, start, goal);
+
+    // Anonymous maze-completion credential (optional, Semaphore-style): the
+    // player proves their `identity_commitment = H(identity)` is a leaf of
+    // an eligible-player tree without revealing `identity`, and the guest
+    // commits a per-(identity, maze) `nullifier_hash` a verifier can use to
+    // reject duplicate submissions without learning who submitted them.
+    // `has_credential` gates this so ungated path proofs keep working with a
+    // zeroed extension instead of requiring every caller to supply one.
+    let has_credential: u8 = env::read();
+    let (identity_root, nullifier_hash) = if has_credential != 0 {
+        let mut identity = [0u8; 32];
+        env::read_slice(&mut identity);
+
+        let mut identity_root = [0u8; 32];
+        env::read_slice(&mut identity_root);
+
+        let mut siblings = [[0u8; 32]; IDENTITY_TREE_DEPTH];
+        for sibling in siblings.iter_mut() {
+            env::read_slice(sibling);
+        }
+        let path_bits: u32 = env::read();
+
+        let commitment = identity_tree::identity_commitment(&identity);
+        if !identity_tree::verify_membership(identity_root, commitment, &(siblings, path_bits)) {
+            is_valid = false;
+        }
+
+        let external_nullifier = identity_tree::external_nullifier(maze_seed);
+        let nullifier_hash = identity_tree::nullifier_hash(&identity, &external_nullifier);
+
+        (identity_root, nullifier_hash)
+    } else {
+        ([0u8; 32], [0u8; 32])
+    };
+
+    commit_result(is_valid, maze_seed, identity_root, nullifier_hash);
+}
 
-    // Commit results to journal
+/// Commit a [`PathJournal`] followed by its nullifier extension
+/// (`identity_root` + `nullifier_hash`, zeroed when no anonymous credential
+/// was supplied) - every exit path from `main` funnels through here so the
+/// journal is always the same fixed layout.
+fn commit_result(is_valid: bool, maze_seed: u32, identity_root: [u8; 32], nullifier_hash: [u8; 32]) {
     let output = PathJournal {
         is_valid: if is_valid { 1 } else { 0 },
         maze_seed,
@@ -155,18 +202,21 @@ fn main() {
 
     env::commit(&output.is_valid);
     env::commit(&output.maze_seed);
+    env::commit_slice(&identity_root);
+    env::commit_slice(&nullifier_hash);
 }
 
 fn verify_maze_solution(
     moves: &[u8],
     grid: &[[u8; 41]; 41], // Fixed-size array for optimal performance
-    grid_size: usize,
-    start_pos: usize,
-    end_pos: usize,
+    grid_rows: usize,
+    grid_cols: usize,
+    start: (usize, usize),
+    goal: (usize, usize),
 ) -> bool {
     // Start position
-    let mut row = start_pos;
-    let mut col = start_pos;
+    let mut row = start.0;
+    let mut col = start.1;
     let mut has_reached_end = false;
 
     // Validate starting position is on a path
@@ -199,7 +249,7 @@ fn verify_maze_solution(
         let next_col = (col as i32).wrapping_add(col_delta) as usize;
 
         // SECURITY: Bounds check prevents out-of-bounds access
-        if next_row >= grid_size || next_col >= grid_size {
+        if next_row >= grid_rows || next_col >= grid_cols {
             return false;
         }
 
@@ -212,8 +262,8 @@ fn verify_maze_solution(
         row = next_row;
         col = next_col;
 
-        // Check if we've reached the end
-        if row == end_pos && col == end_pos {
+        // Check if we've reached the goal
+        if (row, col) == goal {
             has_reached_end = true;
         }
     }
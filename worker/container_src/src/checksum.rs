@@ -0,0 +1,59 @@
+//! BLAKE3 integrity checking for witness uploads and proof downloads, via an
+//! `X-Witness-Checksum: blake3:<hex>` request header and a matching
+//! `X-Proof-Checksum` response header.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
+
+pub const CHECKSUM_HEADER_PREFIX: &str = "blake3:";
+
+/// Parses an `X-Witness-Checksum` header value of the form `blake3:<hex>`.
+/// Returns `None` if the prefix or hex encoding doesn't match, which callers
+/// should treat as a bad request rather than "no checksum supplied".
+pub fn parse_header(value: &str) -> Option<blake3::Hash> {
+    blake3::Hash::from_hex(value.strip_prefix(CHECKSUM_HEADER_PREFIX)?).ok()
+}
+
+pub fn format_header(hash: &blake3::Hash) -> String {
+    format!("{}{}", CHECKSUM_HEADER_PREFIX, hash.to_hex())
+}
+
+/// Wraps an [`AsyncRead`], feeding every byte that passes through into a
+/// [`blake3::Hasher`] as it's read - so a streamed copy (e.g.
+/// `tokio::io::copy` into a temp file) can be integrity-checked without an
+/// extra buffering pass over the data.
+pub struct HashingReader<R> {
+    inner: R,
+    hasher: blake3::Hasher,
+}
+
+impl<R> HashingReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            hasher: blake3::Hasher::new(),
+        }
+    }
+
+    pub fn finalize(&self) -> blake3::Hash {
+        self.hasher.finalize()
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for HashingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = &poll {
+            this.hasher.update(&buf.filled()[before..]);
+        }
+        poll
+    }
+}
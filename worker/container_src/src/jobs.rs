@@ -0,0 +1,120 @@
+//! Backgrounded proof jobs, in the spirit of pict-rs' backgrounded queries:
+//! a job is submitted, the caller polls its status, then fetches the result
+//! once it's `Done`. This exists because a single `bb prove` can take up to
+//! 90s, which is long enough to break behind proxies with shorter idle
+//! timeouts if held open on one HTTP connection.
+
+use axum::body::Bytes;
+use dashmap::DashMap;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::prover::Prover;
+
+/// Status of a single proof job, as returned by `GET /api/prove/status/{id}`.
+#[derive(Clone, Serialize)]
+#[serde(tag = "status", content = "reason", rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Done,
+    Failed(String),
+}
+
+struct JobEntry {
+    status: JobStatus,
+    result: Option<Bytes>,
+    /// Set once the job reaches `Done` or `Failed`, so the reaper can age it out.
+    finished_at: Option<Instant>,
+}
+
+/// Shared job table, held in [`crate::AppState`] and mutated by both the
+/// submitting handler and the spawned worker task for each job.
+pub type JobStore = Arc<DashMap<Uuid, JobEntry>>;
+
+pub fn new_store() -> JobStore {
+    Arc::new(DashMap::new())
+}
+
+/// Record a new `Pending` job and spawn the worker task that drives it
+/// through `Running` to `Done`/`Failed`. `witness_path` is a file the caller
+/// has already fully written to disk; the worker task owns deleting it.
+pub fn submit(store: JobStore, prover: Arc<dyn Prover>, job_id: Uuid, witness_path: PathBuf) {
+    store.insert(
+        job_id,
+        JobEntry {
+            status: JobStatus::Pending,
+            result: None,
+            finished_at: None,
+        },
+    );
+
+    tokio::spawn(async move {
+        if let Some(mut entry) = store.get_mut(&job_id) {
+            entry.status = JobStatus::Running;
+        }
+
+        let outcome = run(&prover, &witness_path).await;
+        let _ = tokio::fs::remove_file(&witness_path).await;
+
+        if let Some(mut entry) = store.get_mut(&job_id) {
+            match outcome {
+                Ok(proof) => {
+                    entry.result = Some(proof);
+                    entry.status = JobStatus::Done;
+                }
+                Err(reason) => entry.status = JobStatus::Failed(reason),
+            }
+            entry.finished_at = Some(Instant::now());
+        }
+    });
+}
+
+async fn run(prover: &Arc<dyn Prover>, witness_path: &Path) -> Result<Bytes, String> {
+    let witness = tokio::fs::read(witness_path)
+        .await
+        .map_err(|e| format!("failed to read witness file: {}", e))?;
+
+    prover
+        .prove(Bytes::from(witness))
+        .await
+        .map(Bytes::from)
+        .map_err(|e| e.to_string())
+}
+
+/// Current status of a job, or `None` if it doesn't exist (never submitted,
+/// or already reaped).
+pub fn status(store: &JobStore, job_id: Uuid) -> Option<JobStatus> {
+    store.get(&job_id).map(|entry| entry.status.clone())
+}
+
+/// The job's result once `Done`. Returns `Ok(None)` if the job exists but
+/// hasn't finished (or failed), and `Err(())` if the job id is unknown.
+pub fn result(store: &JobStore, job_id: Uuid) -> Result<Option<Bytes>, ()> {
+    let entry = store.get(&job_id).ok_or(())?;
+    match &entry.status {
+        JobStatus::Done => Ok(entry.result.clone()),
+        _ => Ok(None),
+    }
+}
+
+/// Periodically drop jobs that finished (`Done` or `Failed`) more than `ttl`
+/// ago, so the store doesn't grow without bound across the worker's uptime.
+pub fn spawn_reaper(store: JobStore, ttl: Duration) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ttl.max(Duration::from_secs(1)));
+        interval.tick().await; // first tick fires immediately; skip it
+
+        loop {
+            interval.tick().await;
+            let now = Instant::now();
+            store.retain(|_, entry| match entry.finished_at {
+                Some(finished_at) => now.duration_since(finished_at) < ttl,
+                None => true,
+            });
+        }
+    });
+}
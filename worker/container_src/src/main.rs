@@ -1,35 +1,63 @@
+mod checksum;
+mod crypto;
+mod jobs;
+mod metrics;
+mod prover;
+
 use axum::{
-    body::Body,
-    extract::Request,
-    http::{header, StatusCode},
+    body::{to_bytes, Body, Bytes},
+    extract::{Path, Request, State},
+    http::{header, HeaderMap, HeaderName, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
+use checksum::HashingReader;
+use crypto::{DecryptingFrames, ServerKeypair};
 use futures::TryStreamExt;
+use jobs::{JobStatus, JobStore};
+use metrics::{InFlightGuard, Metrics};
+use prover::{Prover, ProveError};
 use serde::Serialize;
 use std::path::PathBuf;
-use std::time::Duration;
-use tokio::{
-    fs,
-    process::Command,
-    signal,
-    time::timeout,
-};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::signal;
 use tokio_util::io::StreamReader;
 use tower_http::timeout::TimeoutLayer;
 use uuid::Uuid;
 
+/// Generous upper bound on witness size accepted by `/api/prove`.
+const MAX_WITNESS_BYTES: usize = 64 * 1024 * 1024;
+
 const PORT: u16 = 8080;
-const TEMP_DIR: &str = "/tmp/bb-proofs";
-const CIRCUIT_PATH: &str = "/app/circuit.json";
-const BB_PROVE_TIMEOUT: Duration = Duration::from_secs(90); // 90s for proof generation
+const DEFAULT_PROVER_BACKEND: &str = "bb://";
+
+/// Where async job witnesses are staged while a job is `Pending`/`Running`.
+const JOB_TEMP_DIR: &str = "/tmp/worker-jobs";
+
+/// How long a `Done`/`Failed` job lingers in the job table before the
+/// reaper drops it, overridable via `JOB_TTL_SECS`.
+const DEFAULT_JOB_TTL_SECS: u64 = 600;
+
+const WITNESS_CHECKSUM_HEADER: &str = "x-witness-checksum";
+const PROOF_CHECKSUM_HEADER: &str = "x-proof-checksum";
+
+#[derive(Clone)]
+struct AppState {
+    prover: Arc<dyn Prover>,
+    jobs: JobStore,
+    server_keypair: Arc<ServerKeypair>,
+    metrics: Arc<Metrics>,
+}
 
 // Response types
 #[derive(Serialize)]
 struct HealthResponse {
     status: &'static str,
-    bb_available: bool,
+    backend_available: bool,
 }
 
 #[derive(Serialize)]
@@ -45,10 +73,26 @@ struct NotFoundResponse {
     available_endpoints: &'static [&'static str],
 }
 
+#[derive(Serialize)]
+struct SubmitJobResponse {
+    job_id: Uuid,
+}
+
+#[derive(Serialize)]
+struct PubkeyResponse {
+    /// Hex-encoded X25519 public key; see [`crypto`] for the exchange this feeds into.
+    public_key: String,
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 // Error type
 enum AppError {
     Internal(String),
     BadRequest(String),
+    NotFound(String),
 }
 
 impl IntoResponse for AppError {
@@ -60,6 +104,7 @@ impl IntoResponse for AppError {
                 msg,
             ),
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, "Bad request", msg),
+            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, "Not found", msg),
         };
 
         (
@@ -75,17 +120,43 @@ impl IntoResponse for AppError {
 
 #[tokio::main]
 async fn main() {
-    // Ensure temp directory exists
-    fs::create_dir_all(TEMP_DIR)
+    // Select the proving backend from PROVER_BACKEND (e.g. "bb://", "risc0:///app/guest.elf",
+    // "https://prover-farm.internal/prove"), defaulting to the original bb CLI path.
+    let backend_addr =
+        std::env::var("PROVER_BACKEND").unwrap_or_else(|_| DEFAULT_PROVER_BACKEND.to_string());
+    let prover = prover::from_addr(&backend_addr)
+        .unwrap_or_else(|e| panic!("Invalid PROVER_BACKEND {:?}: {}", backend_addr, e));
+
+    fs::create_dir_all(JOB_TEMP_DIR)
         .await
-        .expect("Failed to create temp directory");
+        .expect("Failed to create job temp directory");
+
+    let job_ttl_secs = std::env::var("JOB_TTL_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_JOB_TTL_SECS);
+    let jobs = jobs::new_store();
+    jobs::spawn_reaper(jobs.clone(), Duration::from_secs(job_ttl_secs));
+
+    let state = AppState {
+        prover: Arc::from(prover),
+        jobs,
+        server_keypair: Arc::new(ServerKeypair::generate()),
+        metrics: Arc::new(Metrics::new()),
+    };
 
     // Build router
     let app = Router::new()
         .route("/api/health", get(health_handler))
+        .route("/api/pubkey", get(pubkey_handler))
         .route("/api/prove", post(prove_handler))
+        .route("/api/prove/async", post(submit_async_handler))
+        .route("/api/prove/status/:id", get(job_status_handler))
+        .route("/api/prove/result/:id", get(job_result_handler))
+        .route("/metrics", get(metrics_handler))
         .fallback(not_found_handler)
-        .layer(TimeoutLayer::new(Duration::from_secs(120)));
+        .layer(TimeoutLayer::new(Duration::from_secs(120)))
+        .with_state(state);
 
     // Minimal startup output
     println!("🚀 Server starting on 0.0.0.0:{}", PORT);
@@ -126,45 +197,167 @@ async fn shutdown_signal() {
     }
 }
 
-async fn health_handler() -> impl IntoResponse {
-    // Fast health check - just verify bb is available
-    let bb_available = Command::new("bb")
-        .arg("--version")
-        .output()
-        .await
-        .map(|output| output.status.success())
-        .unwrap_or(false);
+async fn health_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let backend_available = state.prover.health().await;
+    state
+        .metrics
+        .bb_version_ok
+        .set(backend_available as i64);
 
     Json(HealthResponse {
         status: "ok",
-        bb_available,
+        backend_available,
     })
 }
 
-async fn prove_handler(request: Request) -> Result<impl IntoResponse, AppError> {
-    // Generate unique request ID for file naming
-    let request_id = Uuid::new_v4();
-    let witness_path = PathBuf::from(TEMP_DIR).join(format!("{}.witness", request_id));
+/// GET /metrics
+/// Prometheus text-format export of proof throughput and subprocess health,
+/// for alerting on proving latency regressions and backend unavailability.
+async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.encode(),
+    )
+}
 
-    // Helper to ensure cleanup on all paths
-    struct FileGuard {
-        path: PathBuf,
+/// GET /api/pubkey
+/// Publishes the server's X25519 public key for the encrypted witness
+/// upload mode - see [`crypto`].
+async fn pubkey_handler(State(state): State<AppState>) -> impl IntoResponse {
+    Json(PubkeyResponse {
+        public_key: hex_encode(&state.server_keypair.public_key_bytes()),
+    })
+}
+
+/// Whether the request opted into encrypted witness upload via
+/// `Content-Encoding: chacha20-poly1305-hpke`. Any other (or absent) value
+/// keeps the existing plaintext path.
+fn content_encoding_is_encrypted(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v == crypto::CONTENT_ENCODING)
+}
+
+/// Parses the optional `X-Witness-Checksum: blake3:<hex>` request header.
+/// Absent means "no integrity check requested"; present-but-malformed is a
+/// client error, not something to silently ignore.
+fn parse_checksum_header(headers: &HeaderMap) -> Result<Option<blake3::Hash>, AppError> {
+    let Some(value) = headers.get(WITNESS_CHECKSUM_HEADER) else {
+        return Ok(None);
+    };
+    let value = value.to_str().map_err(|_| {
+        AppError::BadRequest(format!("{} header is not valid UTF-8", WITNESS_CHECKSUM_HEADER))
+    })?;
+    checksum::parse_header(value).map(Some).ok_or_else(|| {
+        AppError::BadRequest(format!(
+            "Malformed {} header: expected blake3:<hex>",
+            WITNESS_CHECKSUM_HEADER
+        ))
+    })
+}
+
+async fn prove_handler(
+    State(state): State<AppState>,
+    request: Request,
+) -> Result<impl IntoResponse, AppError> {
+    let expected_checksum = parse_checksum_header(request.headers())?;
+    let encrypted = content_encoding_is_encrypted(request.headers());
+
+    // Buffer the witness body in memory and hand it straight to the
+    // configured backend; individual Prover impls own any on-disk staging
+    // they need (e.g. BbProver still writes a temp witness file for `bb`).
+    let witness = if encrypted {
+        let body_stream = request.into_body().into_data_stream();
+        let stream_reader = StreamReader::new(
+            body_stream.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)),
+        );
+        let mut frames = DecryptingFrames::open(stream_reader, &state.server_keypair)
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to open encrypted body: {}", e)))?;
+
+        let mut buf = Vec::new();
+        while let Some(plaintext) = frames
+            .next_frame()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to decrypt witness: {}", e)))?
+        {
+            if buf.len() + plaintext.len() > MAX_WITNESS_BYTES {
+                return Err(AppError::BadRequest(format!(
+                    "Witness exceeds maximum size of {} bytes",
+                    MAX_WITNESS_BYTES
+                )));
+            }
+            buf.extend_from_slice(&plaintext);
+        }
+        Bytes::from(buf)
+    } else {
+        to_bytes(request.into_body(), MAX_WITNESS_BYTES)
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to read body: {}", e)))?
+    };
+
+    if witness.is_empty() {
+        return Err(AppError::BadRequest("Empty request body".to_string()));
     }
-    impl Drop for FileGuard {
-        fn drop(&mut self) {
-            let path = self.path.clone();
-            tokio::spawn(async move {
-                let _ = fs::remove_file(&path).await;
-            });
+
+    if let Some(expected) = expected_checksum {
+        let actual = blake3::hash(&witness);
+        if actual != expected {
+            return Err(AppError::BadRequest(format!(
+                "Witness checksum mismatch: expected {}, got {}",
+                checksum::format_header(&expected),
+                checksum::format_header(&actual)
+            )));
         }
     }
-    let _guard = FileGuard {
-        path: witness_path.clone(),
-    };
 
-    // Stream body directly to file instead of buffering in memory
+    state.metrics.witness_bytes_total.inc_by(witness.len() as u64);
+
+    let _in_flight = InFlightGuard::enter(&state.metrics.proofs_in_flight);
+    let prove_started_at = Instant::now();
+    let prove_result = state.prover.prove(witness).await;
+    state
+        .metrics
+        .prove_duration_seconds
+        .observe(prove_started_at.elapsed().as_secs_f64());
+
+    match &prove_result {
+        Ok(_) => state.metrics.proofs_succeeded_total.inc(),
+        Err(ProveError::Timeout(_)) => state.metrics.proofs_timed_out_total.inc(),
+        Err(_) => state.metrics.proofs_failed_total.inc(),
+    }
+
+    let proof_bytes = prove_result
+        .map_err(|e| AppError::Internal(format!("Proof generation failed: {}", e)))?;
+
+    let content_length = proof_bytes.len();
+    let proof_checksum = checksum::format_header(&blake3::hash(&proof_bytes));
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/octet-stream")
+        .header(header::CONTENT_LENGTH, content_length)
+        .header(HeaderName::from_static(PROOF_CHECKSUM_HEADER), proof_checksum)
+        .body(Body::from(proof_bytes))
+        .map_err(|e| AppError::Internal(format!("Failed to build response: {}", e)))
+}
+
+/// POST /api/prove/async
+/// Streams the witness to disk, enqueues a job, and returns immediately
+/// instead of blocking the connection for the full proving duration.
+async fn submit_async_handler(
+    State(state): State<AppState>,
+    request: Request,
+) -> Result<impl IntoResponse, AppError> {
+    let expected_checksum = parse_checksum_header(request.headers())?;
+    let encrypted = content_encoding_is_encrypted(request.headers());
+
+    let job_id = Uuid::new_v4();
+    let witness_path = PathBuf::from(JOB_TEMP_DIR).join(format!("{}.witness", job_id));
+
     let body_stream = request.into_body().into_data_stream();
-    let mut stream_reader = StreamReader::new(
+    let stream_reader = StreamReader::new(
         body_stream.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)),
     );
 
@@ -172,61 +365,108 @@ async fn prove_handler(request: Request) -> Result<impl IntoResponse, AppError>
         .await
         .map_err(|e| AppError::Internal(format!("Failed to create witness file: {}", e)))?;
 
-    let bytes_written = tokio::io::copy(&mut stream_reader, &mut file)
-        .await
-        .map_err(|e| AppError::BadRequest(format!("Failed to write body: {}", e)))?;
+    // Either path hashes plaintext as it's written, so checking the witness
+    // checksum afterward costs nothing extra over the existing streaming path.
+    let (bytes_written, actual_checksum) = if encrypted {
+        let mut frames = DecryptingFrames::open(stream_reader, &state.server_keypair)
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to open encrypted body: {}", e)))?;
+
+        let mut hasher = blake3::Hasher::new();
+        let mut total = 0u64;
+        while let Some(plaintext) = frames
+            .next_frame()
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to decrypt witness: {}", e)))?
+        {
+            if total + plaintext.len() as u64 > MAX_WITNESS_BYTES as u64 {
+                drop(file);
+                let _ = fs::remove_file(&witness_path).await;
+                return Err(AppError::BadRequest(format!(
+                    "Witness exceeds maximum size of {} bytes",
+                    MAX_WITNESS_BYTES
+                )));
+            }
+            hasher.update(&plaintext);
+            file.write_all(&plaintext)
+                .await
+                .map_err(|e| AppError::Internal(format!("Failed to write witness file: {}", e)))?;
+            total += plaintext.len() as u64;
+        }
+        (total, hasher.finalize())
+    } else {
+        let mut hashing_reader = HashingReader::new(stream_reader);
+        let bytes_written = tokio::io::copy(&mut hashing_reader, &mut file)
+            .await
+            .map_err(|e| AppError::BadRequest(format!("Failed to write body: {}", e)))?;
+        (bytes_written, hashing_reader.finalize())
+    };
 
     if bytes_written == 0 {
+        let _ = fs::remove_file(&witness_path).await;
         return Err(AppError::BadRequest("Empty request body".to_string()));
     }
 
-    // Explicitly sync to ensure bb can read the complete file
     file.sync_all()
         .await
         .map_err(|e| AppError::Internal(format!("Failed to sync witness file: {}", e)))?;
-
     drop(file);
 
-    // Execute bb prove - returns raw bytes
-    let proof_bytes = execute_prove(&witness_path)
-        .await
-        .map_err(|e| AppError::Internal(format!("Proof generation failed: {}", e)))?;
+    if let Some(expected) = expected_checksum {
+        if actual_checksum != expected {
+            let _ = fs::remove_file(&witness_path).await;
+            return Err(AppError::BadRequest(format!(
+                "Witness checksum mismatch: expected {}, got {}",
+                checksum::format_header(&expected),
+                checksum::format_header(&actual_checksum)
+            )));
+        }
+    }
 
-    // Get the proof data length for Content-Length header
-    let content_length = proof_bytes.len();
+    jobs::submit(state.jobs, state.prover, job_id, witness_path);
 
-    // Return binary response with proper headers
-    // FileGuard will clean up witness file when function returns
-    Response::builder()
-        .status(StatusCode::OK)
-        .header(header::CONTENT_TYPE, "application/octet-stream")
-        .header(header::CONTENT_LENGTH, content_length)
-        .body(Body::from(proof_bytes))
-        .map_err(|e| AppError::Internal(format!("Failed to build response: {}", e)))
+    Ok((StatusCode::ACCEPTED, Json(SubmitJobResponse { job_id })))
 }
 
-async fn execute_prove(witness_path: &PathBuf) -> Result<Vec<u8>, String> {
-    let prove_future = Command::new("bb")
-        .args(&["prove", "-b", CIRCUIT_PATH, "-w"])
-        .arg(witness_path)
-        .args(&["-o", "-"])
-        .output();
+/// GET /api/prove/status/{id}
+async fn job_status_handler(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    jobs::status(&state.jobs, job_id)
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound(format!("No job with id {}", job_id)))
+}
 
-    let output = timeout(BB_PROVE_TIMEOUT, prove_future)
-        .await
-        .map_err(|_| format!("bb prove timed out after {}s", BB_PROVE_TIMEOUT.as_secs()))?
-        .map_err(|e| format!("Failed to execute bb: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!(
-            "bb prove failed (exit {}): {}",
-            output.status.code().unwrap_or(-1),
-            stderr
-        ));
+/// GET /api/prove/result/{id}
+async fn job_result_handler(
+    State(state): State<AppState>,
+    Path(job_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let status = jobs::status(&state.jobs, job_id)
+        .ok_or_else(|| AppError::NotFound(format!("No job with id {}", job_id)))?;
+
+    match status {
+        JobStatus::Done => {
+            let proof_bytes = jobs::result(&state.jobs, job_id)
+                .ok()
+                .flatten()
+                .ok_or_else(|| AppError::Internal("job marked done but missing result".to_string()))?;
+            let proof_checksum = checksum::format_header(&blake3::hash(&proof_bytes));
+
+            Response::builder()
+                .status(StatusCode::OK)
+                .header(header::CONTENT_TYPE, "application/octet-stream")
+                .header(header::CONTENT_LENGTH, proof_bytes.len())
+                .header(HeaderName::from_static(PROOF_CHECKSUM_HEADER), proof_checksum)
+                .body(Body::from(proof_bytes))
+                .map_err(|e| AppError::Internal(format!("Failed to build response: {}", e)))
+        }
+        JobStatus::Failed(reason) => Err(AppError::Internal(format!("job failed: {}", reason))),
+        JobStatus::Pending | JobStatus::Running => {
+            Err(AppError::BadRequest("job has not finished yet".to_string()))
+        }
     }
-
-    Ok(output.stdout)
 }
 
 async fn not_found_handler() -> impl IntoResponse {
@@ -234,7 +474,15 @@ async fn not_found_handler() -> impl IntoResponse {
         StatusCode::NOT_FOUND,
         Json(NotFoundResponse {
             error: "Not found",
-            available_endpoints: &["/api/health", "/api/prove"],
+            available_endpoints: &[
+                "/api/health",
+                "/api/pubkey",
+                "/api/prove",
+                "/api/prove/async",
+                "/api/prove/status/{id}",
+                "/api/prove/result/{id}",
+                "/metrics",
+            ],
         }),
     )
 }
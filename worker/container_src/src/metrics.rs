@@ -0,0 +1,131 @@
+//! Prometheus metrics for proof throughput and subprocess health, exported in
+//! text exposition format at `GET /metrics`.
+//!
+//! Held as a single `Arc<Metrics>` in `AppState` so every handler records
+//! into the same registry, the same way `jobs::JobStore` is shared.
+
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounter, IntGauge, Opts, Registry, TextEncoder};
+
+pub struct Metrics {
+    registry: Registry,
+    /// Wall-clock duration of each prove attempt, success or failure alike.
+    pub prove_duration_seconds: Histogram,
+    pub proofs_succeeded_total: IntCounter,
+    /// Failures other than a timeout (backend error, malformed witness, etc).
+    pub proofs_failed_total: IntCounter,
+    pub proofs_timed_out_total: IntCounter,
+    /// Total witness bytes accepted across all `/api/prove` requests.
+    pub witness_bytes_total: IntCounter,
+    /// Number of `/api/prove` requests currently being proved.
+    pub proofs_in_flight: IntGauge,
+    /// Whether the last `bb --version` health probe succeeded (1) or not (0).
+    pub bb_version_ok: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let prove_duration_seconds = Histogram::with_opts(HistogramOpts::new(
+            "worker_prove_duration_seconds",
+            "Wall-clock duration of proof generation, in seconds",
+        ))
+        .expect("static histogram opts are valid");
+
+        let proofs_succeeded_total = IntCounter::with_opts(Opts::new(
+            "worker_proofs_succeeded_total",
+            "Total number of proofs generated successfully",
+        ))
+        .expect("static counter opts are valid");
+
+        let proofs_failed_total = IntCounter::with_opts(Opts::new(
+            "worker_proofs_failed_total",
+            "Total number of proof attempts that failed, excluding timeouts",
+        ))
+        .expect("static counter opts are valid");
+
+        let proofs_timed_out_total = IntCounter::with_opts(Opts::new(
+            "worker_proofs_timed_out_total",
+            "Total number of proof attempts that hit the prover timeout",
+        ))
+        .expect("static counter opts are valid");
+
+        let witness_bytes_total = IntCounter::with_opts(Opts::new(
+            "worker_witness_bytes_total",
+            "Total witness bytes accepted across all prove requests",
+        ))
+        .expect("static counter opts are valid");
+
+        let proofs_in_flight = IntGauge::with_opts(Opts::new(
+            "worker_proofs_in_flight",
+            "Number of prove requests currently being processed",
+        ))
+        .expect("static gauge opts are valid");
+
+        let bb_version_ok = IntGauge::with_opts(Opts::new(
+            "worker_bb_version_ok",
+            "Whether the last `bb --version` health probe last succeeded (1) or not (0)",
+        ))
+        .expect("static gauge opts are valid");
+
+        for collector in [
+            Box::new(prove_duration_seconds.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(proofs_succeeded_total.clone()),
+            Box::new(proofs_failed_total.clone()),
+            Box::new(proofs_timed_out_total.clone()),
+            Box::new(witness_bytes_total.clone()),
+            Box::new(proofs_in_flight.clone()),
+            Box::new(bb_version_ok.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metric names are unique and well-formed");
+        }
+
+        Self {
+            registry,
+            prove_duration_seconds,
+            proofs_succeeded_total,
+            proofs_failed_total,
+            proofs_timed_out_total,
+            witness_bytes_total,
+            proofs_in_flight,
+            bb_version_ok,
+        }
+    }
+
+    /// Renders every registered collector in Prometheus text exposition format.
+    pub fn encode(&self) -> String {
+        let metric_families = self.registry.gather();
+        let mut buf = Vec::new();
+        TextEncoder::new()
+            .encode(&metric_families, &mut buf)
+            .expect("in-memory metrics encoding cannot fail");
+        String::from_utf8(buf).expect("prometheus text output is valid utf-8")
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Keeps `proofs_in_flight` accurate across early returns (`?`) by
+/// decrementing on drop instead of requiring every exit path to remember to.
+pub struct InFlightGuard<'a> {
+    gauge: &'a IntGauge,
+}
+
+impl<'a> InFlightGuard<'a> {
+    pub fn enter(gauge: &'a IntGauge) -> Self {
+        gauge.inc();
+        Self { gauge }
+    }
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.gauge.dec();
+    }
+}
@@ -0,0 +1,273 @@
+//! Pluggable proving backends, selected at startup from a `PROVER_BACKEND`
+//! address string (`bb://`, `risc0://`, `grpc://host:port`, `http(s)://...`),
+//! in the style of tvix's `from_addr` constructors. This lets operators swap
+//! proving engines per deployment without recompiling the worker.
+
+use axum::body::Bytes;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::time::timeout;
+
+/// Error produced by a [`Prover`] implementation.
+#[derive(Debug)]
+pub enum ProveError {
+    /// The backend ran but proof generation itself failed.
+    Internal(String),
+    /// The backend didn't respond within its configured timeout.
+    Timeout(String),
+    /// `PROVER_BACKEND` named a scheme no implementation handles.
+    UnsupportedBackend(String),
+}
+
+impl fmt::Display for ProveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProveError::Internal(msg) => write!(f, "proof generation failed: {}", msg),
+            ProveError::Timeout(msg) => write!(f, "proof generation timed out: {}", msg),
+            ProveError::UnsupportedBackend(addr) => write!(f, "unsupported prover backend: {}", addr),
+        }
+    }
+}
+
+impl std::error::Error for ProveError {}
+
+/// A proving backend: takes a witness, returns the raw proof bytes.
+#[async_trait::async_trait]
+pub trait Prover: Send + Sync {
+    async fn prove(&self, witness: Bytes) -> Result<Vec<u8>, ProveError>;
+
+    /// Cheap reachability check for `/api/health`. Defaults to `true` since
+    /// most backends have no equivalent of `bb --version` to probe.
+    async fn health(&self) -> bool {
+        true
+    }
+}
+
+/// Construct a [`Prover`] from an address string, dispatching on its scheme.
+///
+/// Supported schemes:
+/// - `bb://` - shell out to the `bb` CLI against a local circuit (the
+///   original, and still default, behavior)
+/// - `risc0://` - drive a RISC Zero guest in-process
+/// - `http://` / `https://` - forward the witness to a remote prover farm
+///   over HTTP, returning its response body as the proof
+/// - `grpc://host:port` - reserved for a gRPC-based remote prover; not yet
+///   implemented (see [`GrpcProver`])
+pub fn from_addr(addr: &str) -> Result<Box<dyn Prover>, ProveError> {
+    if let Some(rest) = addr.strip_prefix("bb://") {
+        let circuit_path = if rest.is_empty() {
+            PathBuf::from(BbProver::DEFAULT_CIRCUIT_PATH)
+        } else {
+            PathBuf::from(rest)
+        };
+        return Ok(Box::new(BbProver::new(circuit_path)));
+    }
+
+    if let Some(rest) = addr.strip_prefix("risc0://") {
+        return Ok(Box::new(Risc0Prover::new(rest.to_string())));
+    }
+
+    if addr.starts_with("http://") || addr.starts_with("https://") {
+        return Ok(Box::new(HttpProver::new(addr.to_string())));
+    }
+
+    if let Some(rest) = addr.strip_prefix("grpc://") {
+        return Ok(Box::new(GrpcProver::new(rest.to_string())));
+    }
+
+    Err(ProveError::UnsupportedBackend(addr.to_string()))
+}
+
+/// Shells out to the `bb` CLI against a local circuit file - the original
+/// proving path, unchanged in behavior from before this was a trait.
+pub struct BbProver {
+    circuit_path: PathBuf,
+    temp_dir: PathBuf,
+    timeout: Duration,
+}
+
+impl BbProver {
+    const DEFAULT_CIRCUIT_PATH: &'static str = "/app/circuit.json";
+    const DEFAULT_TEMP_DIR: &'static str = "/tmp/bb-proofs";
+    const PROVE_TIMEOUT: Duration = Duration::from_secs(90);
+
+    pub fn new(circuit_path: PathBuf) -> Self {
+        Self {
+            circuit_path,
+            temp_dir: PathBuf::from(Self::DEFAULT_TEMP_DIR),
+            timeout: Self::PROVE_TIMEOUT,
+        }
+    }
+
+    /// Whether the `bb` binary is reachable, for the `/api/health` check.
+    pub async fn is_available(&self) -> bool {
+        Command::new("bb")
+            .arg("--version")
+            .output()
+            .await
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+}
+
+#[async_trait::async_trait]
+impl Prover for BbProver {
+    async fn health(&self) -> bool {
+        self.is_available().await
+    }
+
+    async fn prove(&self, witness: Bytes) -> Result<Vec<u8>, ProveError> {
+        tokio::fs::create_dir_all(&self.temp_dir)
+            .await
+            .map_err(|e| ProveError::Internal(format!("failed to create temp directory: {}", e)))?;
+
+        let request_id = uuid::Uuid::new_v4();
+        let witness_path = self.temp_dir.join(format!("{}.witness", request_id));
+
+        tokio::fs::write(&witness_path, &witness)
+            .await
+            .map_err(|e| ProveError::Internal(format!("failed to write witness file: {}", e)))?;
+
+        let result = self.run_bb_prove(&witness_path).await;
+
+        let _ = tokio::fs::remove_file(&witness_path).await;
+
+        result
+    }
+}
+
+impl BbProver {
+    async fn run_bb_prove(&self, witness_path: &PathBuf) -> Result<Vec<u8>, ProveError> {
+        let prove_future = Command::new("bb")
+            .args(&["prove", "-b"])
+            .arg(&self.circuit_path)
+            .arg("-w")
+            .arg(witness_path)
+            .args(&["-o", "-"])
+            .output();
+
+        let output = timeout(self.timeout, prove_future)
+            .await
+            .map_err(|_| ProveError::Timeout(format!("bb prove timed out after {}s", self.timeout.as_secs())))?
+            .map_err(|e| ProveError::Internal(format!("failed to execute bb: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(ProveError::Internal(format!(
+                "bb prove failed (exit {}): {}",
+                output.status.code().unwrap_or(-1),
+                stderr
+            )));
+        }
+
+        Ok(output.stdout)
+    }
+}
+
+/// Drives a RISC Zero guest in-process instead of shelling out, for
+/// deployments that want the zkVM proving path baked into the worker
+/// itself rather than behind a separate circuit-risczero service.
+///
+/// `elf_path` names the guest ELF to load (authority/path portion of the
+/// `risc0://` address, e.g. `risc0:///app/guest.elf`).
+pub struct Risc0Prover {
+    elf_path: String,
+}
+
+impl Risc0Prover {
+    pub fn new(elf_path: String) -> Self {
+        Self { elf_path }
+    }
+}
+
+#[async_trait::async_trait]
+impl Prover for Risc0Prover {
+    async fn prove(&self, witness: Bytes) -> Result<Vec<u8>, ProveError> {
+        let elf_path = self.elf_path.clone();
+        tokio::task::spawn_blocking(move || {
+            let elf = std::fs::read(&elf_path)
+                .map_err(|e| ProveError::Internal(format!("failed to read guest ELF {}: {}", elf_path, e)))?;
+
+            let env = risc0_zkvm::ExecutorEnv::builder()
+                .write_slice(&witness)
+                .build()
+                .map_err(|e| ProveError::Internal(format!("failed to build executor env: {}", e)))?;
+
+            let prover = risc0_zkvm::default_prover();
+            let prove_info = prover
+                .prove(env, &elf)
+                .map_err(|e| ProveError::Internal(format!("risc0 proving failed: {}", e)))?;
+
+            bincode::serialize(&prove_info.receipt)
+                .map_err(|e| ProveError::Internal(format!("failed to serialize receipt: {}", e)))
+        })
+        .await
+        .map_err(|e| ProveError::Internal(format!("risc0 prover task panicked: {}", e)))?
+    }
+}
+
+/// Forwards the witness to a remote prover farm over HTTP, returning its
+/// response body as the proof.
+pub struct HttpProver {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+impl HttpProver {
+    pub fn new(endpoint: String) -> Self {
+        Self {
+            endpoint,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Prover for HttpProver {
+    async fn prove(&self, witness: Bytes) -> Result<Vec<u8>, ProveError> {
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .body(witness)
+            .send()
+            .await
+            .map_err(|e| ProveError::Internal(format!("request to {} failed: {}", self.endpoint, e)))?;
+
+        if !response.status().is_success() {
+            return Err(ProveError::Internal(format!(
+                "remote prover at {} returned {}",
+                self.endpoint,
+                response.status()
+            )));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|b| b.to_vec())
+            .map_err(|e| ProveError::Internal(format!("failed to read response from {}: {}", self.endpoint, e)))
+    }
+}
+
+/// Reserved for a gRPC-based remote prover. Not yet implemented - there's no
+/// proto service defined for the remote farm yet, so this exists as a
+/// resolvable `grpc://` address that fails loudly instead of silently
+/// falling through to another backend.
+pub struct GrpcProver {
+    addr: String,
+}
+
+impl GrpcProver {
+    pub fn new(addr: String) -> Self {
+        Self { addr }
+    }
+}
+
+#[async_trait::async_trait]
+impl Prover for GrpcProver {
+    async fn prove(&self, _witness: Bytes) -> Result<Vec<u8>, ProveError> {
+        Err(ProveError::UnsupportedBackend(format!("grpc://{} (gRPC prover backend not yet implemented)", self.addr)))
+    }
+}